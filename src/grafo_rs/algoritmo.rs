@@ -1,7 +1,65 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+
 use crate::grafo_rs::{Arbol, Grafo, Arista, 
                     AristaT, PesoT, VerticeT, GrafoT,
                     Etiquetado};
 
+pub mod caminos;
+pub use caminos::{bellman_ford, floyd_warshall, johnson, ErrorCamino, MatrizDistancias, ResultadoCamino};
+
+pub mod kruskal;
+pub use kruskal::{bosque_peso_minimo, bosque_peso_maximo};
+
+pub mod componentes;
+pub use componentes::{componentes_conexas, subgrafos_conexos, etiquetado_componentes, num_componentes, es_conexo};
+pub use componentes::{componentes_fuertemente_conexas, condensacion};
+
+pub mod orden_topologico;
+pub use orden_topologico::{orden_topologico, es_aciclico};
+
+pub mod camino_critico;
+pub use camino_critico::{arbol_camino_maximo, camino_critico, AnalisisCritico};
+
+pub mod recorrido;
+pub use recorrido::{Bfs, Dfs, Visitante, Control, visitar_anchura, visitar_profundidad};
+
+pub mod biconexas;
+pub use biconexas::{biconexas, AnalisisBiconexo, NodoBloque};
+
+pub mod euler;
+pub use euler::{camino_euleriano, circuito_euleriano, camino_euleriano_dirigido, circuito_euleriano_dirigido};
+
+pub mod cartero;
+pub use cartero::{cartero_chino, cartero_chino_dirigido, RecorridoCartero};
+
+pub mod hamilton;
+pub use hamilton::{camino_hamiltoniano, ciclo_hamiltoniano, MetodoHamilton, Presupuesto, ResultadoHamilton, LIMITE_HELD_KARP};
+
+pub mod viajante;
+pub use viajante::{vecino_mas_cercano, insercion_mas_barata, christofides, dos_opt, or_opt, RutaViajante};
+
+pub mod ciclos;
+pub use ciclos::{buscar_ciclo, cintura, base_ciclos};
+
+pub mod excentricidad;
+pub use excentricidad::{analisis_distancias, analisis_distancias_ponderado, AnalisisDistancias};
+
+pub mod coloracion;
+pub use coloracion::{coloracion_voraz, dsatur, numero_cromatico, es_coloracion_propia, OrdenColoracion};
+
+pub mod hopcroft_karp;
+pub use hopcroft_karp::{hopcroft_karp, es_maximo, es_perfecto};
+
+pub mod hungaro;
+pub use hungaro::{hungaro, Asignacion, ObjetivoAsignacion};
+
+pub mod edmonds;
+pub use edmonds::{emparejamiento_maximo, emparejamiento_peso_maximo};
+
+pub mod konig;
+pub use konig::{cubrimiento_minimo, conjunto_independiente_maximo, violacion_hall};
+
 #[cfg(test)]
 mod tests;
 
@@ -42,7 +100,8 @@ pub fn comprobar_sucesion(sucesion: &Vec<isize>) -> bool
 ///
 /// PRE: Cierto
 /// POST: Arbol generador de peso minimo
-/// NOTA: Implementacion del algoritmo de Prim. Requere que el Peso tenga un orden parcial definido
+/// NOTA: Implementacion del algoritmo de Prim. Requere que el Peso tenga un orden parcial definido.
+/// Solo genera el arbol de la componente conexa de la primera arista. Para grafos no conexos, vease [`bosque_peso_minimo`]
 /// 
 pub fn arbol_peso_minimo<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>) -> Option<Arbol<Grafo<Vertice, Peso>, Vertice, Peso>>
 where Vertice: VerticeT, Peso: PesoT + Ord
@@ -101,54 +160,53 @@ where Vertice: VerticeT, Peso: PesoT + Ord
 }
 
 ///
-/// PRE: Grafo y vertice raiz
-/// POST: Arbol de busqueda en anchura en el vertice si esta en el grafo. None eoc
+/// PRE: Grafo y vertice raiz del grafo
+/// POST: Vertices alcanzables desde la raiz por orden de visita en anchura, junto con la arista
+/// por la que se alcanza cada uno (None para la raiz)
 /// 
-pub fn arbol_anchura<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>, v0: &Vertice) -> Option<Arbol<Grafo<Vertice, Peso>, Vertice, Peso>>
-where Vertice: VerticeT, Peso: PesoT
+fn recorrido_anchura<'a, Graf, Vertice, Peso>(grafo: &'a Graf, v0: &'a Vertice) -> Vec<(&'a Vertice, Option<&'a Graf::Arista>)>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
 {
-    let mut arbol: Grafo<Vertice, Peso> = Grafo::new();
-    // Variables temporales
-    let mut vertices_visitados: Vec<&Vertice> = vec![v0];
-    let mut aristas_frontera: Vec<&Arista<Vertice, Peso>> = grafo.get_aristas().iter()
-                            .filter(|x| x.arista_contiene_vertice(v0))
-                            .collect();
-    let mut vertices_proximos: Vec<&Vertice> = aristas_frontera.iter()
-                            .map(|x| x.other(v0).unwrap())
-                            .collect();
-    
-    while !aristas_frontera.is_empty()
+    let mut recorrido = vec![(v0, None)];
+    let mut vertices_visitados: HashSet<&Vertice> = HashSet::from([v0]);
+    let mut cola: VecDeque<&Vertice> = VecDeque::from([v0]);
+
+    while let Some(vertice_visitado) = cola.pop_front()
     {
-        // Añadimos las aristas frontera
-        for (pos, arista) in aristas_frontera.into_iter().enumerate()
+        for arista in grafo.aristas_accesibles(vertice_visitado)
         {
-            let vertice_contrario = vertices_proximos.get(pos).unwrap();
-            if !vertices_visitados.contains(&vertice_contrario)
+            let otro = arista.other(vertice_visitado).unwrap();
+            if vertices_visitados.insert(otro)
             {
-                vertices_visitados.push(vertice_contrario);
-                arbol.add_aristas(vec![arista.clone()]);
+                recorrido.push((otro, Some(arista)));
+                cola.push_back(otro);
             }
         }
-        // Actualizamos las aristas frontera
-        let mut nuevas_aristas: Vec<&Arista<Vertice, Peso>> = vec![];
-        let mut nuevos_vertices: Vec<&Vertice> = vec![];
-        for vertice in vertices_proximos.into_iter()
+    }
+    recorrido
+}
+
+///
+/// PRE: Grafo y vertice raiz
+/// POST: Arbol de busqueda en anchura en el vertice si esta en el grafo. None eoc
+/// NOTA: Solo se recorren las aristas accesibles, por lo que en un Digrafo se siguen los arcos en su sentido.
+/// Si la raiz no tiene aristas accesibles, el arbol contiene unicamente a la raiz como vertice aislado
+/// 
+pub fn arbol_anchura<Graf, Vertice, Peso>(grafo: &Graf, v0: &Vertice) -> Option<Arbol<Graf, Vertice, Peso>>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
+{
+    let raiz = *grafo.get_vertices().iter().find(|x| **x == v0)?;
+    let mut arbol = Graf::new();
+    for (_, arista) in recorrido_anchura(grafo, raiz)
+    {
+        if let Some(arista) = arista
         {
-            let mut aristas_vertice: Vec<&Arista<Vertice, Peso>> = grafo.get_aristas().iter()
-                            .filter(|x| x.arista_contiene_vertice(vertice))
-                            .filter(|x| !vertices_visitados.contains(&x.other(vertice).unwrap()))
-                            .collect();
-            nuevos_vertices.append(&mut aristas_vertice.iter()
-                            .map(|x| x.other(vertice).unwrap()).collect());
-            nuevas_aristas.append(&mut aristas_vertice);
+            arbol.add_aristas(vec![arista.clone()]);
         }
-        aristas_frontera = nuevas_aristas;
-        // Actualizamos los nuevos vertices
-        vertices_proximos = nuevos_vertices;
     }
     if arbol.get_aristas().is_empty()
     {
-        return None;
+        arbol.add_vertices(vec![v0.clone()]);
     }
 
     Some(Arbol::from_grafo(arbol, v0.clone()))
@@ -156,141 +214,133 @@ where Vertice: VerticeT, Peso: PesoT
 
 ///
 /// PRE: Grafo y referencia a Vertice
-/// POST: Terna de Arbol de busqueda de profundidad con la raiz proporcionada y etiquetado.
-/// Si la raiz no esta en el grafo, devuelve None
+/// POST: Terna de Arbol de busqueda de profundidad con la raiz proporcionada y etiquetado
+/// con el orden de descubrimiento de cada vertice. Si la raiz no esta en el grafo, devuelve None
+/// NOTA: Solo se recorren las aristas accesibles, por lo que en un Digrafo se siguen los arcos en su sentido.
+/// Si la raiz no tiene aristas accesibles, el arbol contiene unicamente a la raiz como vertice aislado
 /// 
-pub fn arbol_profundidad<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>, v0: &Vertice) -> Option<(Arbol<Grafo<Vertice, Peso>, Vertice, Peso>, Etiquetado<Vertice>)>
-where Vertice: VerticeT, Peso: PesoT
+pub fn arbol_profundidad<Graf, Vertice, Peso>(grafo: &Graf, v0: &Vertice) -> Option<(Arbol<Graf, Vertice, Peso>, Etiquetado<Vertice>)>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
 {
-    let mut arbol = Grafo::new();
+    let raiz = *grafo.get_vertices().iter().find(|x| **x == v0)?;
+    let mut arbol = Graf::new();
     let mut df = Etiquetado::new(Some("df"));
 
-    // Definimos una pila para backtracking y un vector de vertices visitados
-    let mut backtrack: Vec<&Vertice> = vec![];
-    let mut vertices_visitados: Vec<&Vertice> = vec![];
-    let mut vertice_visitado = v0;
+    // Definimos una pila para backtracking con las aristas pendientes de cada vertice
+    let mut vertices_visitados: HashSet<&Vertice> = HashSet::from([raiz]);
+    let mut backtrack = vec![(raiz, grafo.aristas_accesibles(raiz).into_iter())];
+    df.add_vertice(raiz.clone(), 0);
 
     // Definimos contador para df
-    let mut i: isize = 0;
+    let mut i: isize = 1;
 
-    while arbol.get_aristas().len() < grafo.size() - 1
+    while let Some((vertice_visitado, aristas_pendientes)) = backtrack.last_mut()
     {
-        // Seleccionamos una arista al azar
-        let mut aristas_vecinas: Vec<&Arista<Vertice, Peso>> = grafo.get_aristas().iter()
-                                        .filter(|x| x.arista_contiene_vertice(vertice_visitado))    
-                                        .collect();
-        
-        for vertice in vertices_visitados.iter().filter(|x| **x != vertice_visitado)
-        {
-            aristas_vecinas.retain(|x| !x.arista_contiene_vertice(vertice));
-        }
-
-        // Añadimos vertice al etiquetado
-        // TODO: Evitar contaminar el etiquetado y el vector de vertices visitados con vertices dobles
-        df.add_vertice(vertice_visitado.clone(), i);
-        i += 1;
-        vertices_visitados.push(vertice_visitado);
-
-        if !aristas_vecinas.is_empty()
-        {
-            let arista_seleccionada = *aristas_vecinas.get(0).unwrap();
-            backtrack.push(vertice_visitado);
-            vertice_visitado = arista_seleccionada.other(vertice_visitado).unwrap();
-            arbol.add_aristas(vec![arista_seleccionada.clone()]);
-        }
-        else 
-        {
-            // Si no hay mas opciones para avanzar, usamos la pila
-            vertice_visitado = match backtrack.pop() {
-                Some(e) => e,
-                None => { break; }
-            };
+        let vertice_visitado = *vertice_visitado;
+        // Avanzamos por la primera arista que lleve a un vertice no visitado
+        let siguiente = aristas_pendientes
+                            .find(|x| !vertices_visitados.contains(x.other(vertice_visitado).unwrap()));
+        match siguiente {
+            Some(arista) => {
+                let otro = arista.other(vertice_visitado).unwrap();
+                vertices_visitados.insert(otro);
+                df.add_vertice(otro.clone(), i);
+                i += 1;
+                arbol.add_aristas(vec![arista.clone()]);
+                backtrack.push((otro, grafo.aristas_accesibles(otro).into_iter()));
+            },
+            // Si no hay mas opciones para avanzar, retrocedemos
+            None => { backtrack.pop(); }
         }
     }
+    if arbol.get_aristas().is_empty()
+    {
+        arbol.add_vertices(vec![v0.clone()]);
+    }
 
     Some((Arbol::from_grafo(arbol, v0.clone()), df))
 }
 
+///
+/// PRE: Grafo y vertice
+/// POST: Vector con referencias a los vertices alcanzables desde v0, incluido el propio v0, por orden de
+/// visita en anchura. None si v0 no esta en el grafo
+/// NOTA: En un Digrafo se siguen los arcos en su sentido
+/// 
+pub fn alcanzables<'a, Graf, Vertice, Peso>(grafo: &'a Graf, v0: &Vertice) -> Option<Vec<&'a Vertice>>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT, Graf::Arista: 'a
+{
+    Some(Bfs::new(grafo, v0)?.collect())
+}
+
 ///
 /// PRE: Grafo y vertice desde se va a calcular los caminos minimos
 /// POST: Si el vertice esta en el grafo, terna de Arbol que contiene los caminos minimos y
 /// etiquetado con las longitudes. None si no pertenece al grafo o si faltan pesos
-/// NOTA: Implementacion del algoritmo de Dijkstra. Se requiere que Peso implemente un orden parcial
+/// NOTA: Implementacion del algoritmo de Dijkstra con una cola de prioridad. Se requiere que Peso implemente un orden total
 /// 
 pub fn arbol_camino_minimo<Graf, Vertice, Peso>(grafo: &Graf, v0: &Vertice) -> Option<(Arbol<Graf, Vertice, Peso>, Etiquetado<Vertice>)>
 where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT + Ord
 {
     let vertices = grafo.get_vertices();
+    let ids: HashMap<&Vertice, usize> = vertices.iter().enumerate()
+                                            .map(|(i, v)| (*v, i))
+                                            .collect();
+    let id0 = *ids.get(v0)?;
 
     let mut arbol = Graf::new();
     let mut distancia = Etiquetado::new(Some("Distancias"));
 
-    // Variables temporales
-    let mut distancia_temporal: Vec<(Option<Peso>, &Vertice)> = vec![(None, v0); vertices.len()];
-    let mut vertice_visitado = v0;
-    let mut acarreo_visitado = Peso::elemento_neutro();
-    let mut vertices_visitados: Vec<&Vertice> = vec![v0];
+    // Distancias temporales y arista por la que se alcanza cada vertice
+    let mut distancia_temporal: Vec<Option<Peso>> = vec![None; vertices.len()];
+    let mut aristas_previas: Vec<Option<&Graf::Arista>> = vec![None; vertices.len()];
+    let mut vertices_visitados = vec![false; vertices.len()];
+    let mut cola = BinaryHeap::new();
 
-    if !vertices.contains(&v0)
+    distancia_temporal[id0] = Some(Peso::elemento_neutro());
+    cola.push(Reverse((Peso::elemento_neutro(), id0)));
+
+    while let Some(Reverse((acarreo_visitado, id))) = cola.pop()
     {
-        return None;
-    }
+        // Las entradas obsoletas de la cola se descartan
+        if vertices_visitados[id]
+        {
+            continue;
+        }
+        vertices_visitados[id] = true;
+        let vertice_visitado = vertices[id];
+        distancia.add_vertice(vertice_visitado.clone(), acarreo_visitado.to_isize());
+        if let Some(arista) = aristas_previas[id]
+        {
+            arbol.add_aristas(vec![arista.clone()]);
+        }
 
-    let mut aristas_vecinas: Vec<&Graf::Arista> = grafo.get_aristas().iter()
-                                            .filter(|x| x.es_accesible(vertice_visitado))
-                                            .filter(|x| !vertices_visitados.contains(&x.other(vertice_visitado).unwrap()))
-                                            .collect();
-    while !aristas_vecinas.is_empty() {
-    
-        for arista in aristas_vecinas.iter()
+        for arista in grafo.aristas_accesibles(vertice_visitado)
         {
+            let otro = ids[arista.other(vertice_visitado).unwrap()];
+            if vertices_visitados[otro]
+            {
+                continue;
+            }
             // Comprobamos si el peso es negativo
-            if arista.get_peso()?.es_negativo()
+            let peso = arista.get_peso()?;
+            if peso.es_negativo()
             {
                 return None;
             }
             // Evaluamos las distancias temporales
-            let otro = arista.other(vertice_visitado).unwrap();
-            let otro = vertices.iter().position(|x| **x == *otro).unwrap();
-            let otro = distancia_temporal.get_mut(otro).unwrap();
-            match &otro.0 {
-                Some(d) => {
-                    let nueva_distancia = acarreo_visitado.suma(arista.get_peso()?);
-                    if d > &nueva_distancia
-                    {
-                        otro.0 = Some(nueva_distancia);
-                        otro.1 = vertice_visitado;
-                    }
-                },
-                None => {
-                    otro.0 = Some(acarreo_visitado.suma(arista.get_peso()?));
-                    otro.1 = vertice_visitado;
-                }
+            let nueva_distancia = acarreo_visitado.suma(peso);
+            let mejora = match &distancia_temporal[otro] {
+                Some(d) => *d > nueva_distancia,
+                None => true
+            };
+            if mejora
+            {
+                distancia_temporal[otro] = Some(nueva_distancia.clone());
+                aristas_previas[otro] = Some(arista);
+                cola.push(Reverse((nueva_distancia, otro)));
             }
         }
-        // Elegimos el vertice con menor distancia y lo añadimos al arbol
-        let menor_distancia = distancia_temporal.iter()
-                                    .enumerate()
-                                    .filter(|x| x.1.0.is_some())
-                                    .min_by(|x, y| x.1.0.cmp(&y.1.0)).unwrap();
-        let menor_distancia = (menor_distancia.0, menor_distancia.1.0.as_ref().unwrap(), menor_distancia.1.1);
-        vertices_visitados.push(vertice_visitado);
-        vertice_visitado = vertices[menor_distancia.0];
-        distancia.add_vertice(vertice_visitado.clone(), menor_distancia.1.to_isize());
-        acarreo_visitado = menor_distancia.1.clone();
-        let min_arista = grafo.get_aristas().iter()
-                            .filter(|x| x.arista_contiene_vertice(vertice_visitado) && x.arista_contiene_vertice(menor_distancia.2))
-                            .min_by(|x, y| x.get_peso().cmp(&y.get_peso())).unwrap();
-        arbol.add_aristas(vec![min_arista.clone()]);
-        // Lo quitamos del vector de distancia temporal
-        let menor_distancia_pos = menor_distancia.0;
-        distancia_temporal[menor_distancia_pos] = (None, v0);
-        // Actualizamos las aristas vecinas
-        aristas_vecinas = grafo.get_aristas().iter()
-                                            .filter(|x| x.es_accesible(vertice_visitado))
-                                            .filter(|x| !vertices_visitados.contains(&x.other(vertice_visitado).unwrap()))
-                                            .collect();
     }
-    distancia.add_vertice(v0.clone(), 0);
     Some((Arbol::from_grafo(arbol, v0.clone()), distancia))
 }
@@ -0,0 +1,342 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+
+use crate::grafo_rs::{Arbol, Arista, AristaT, Bosque, Etiquetado, Grafo, GrafoT, NoPeso, PesoT, VerticeT};
+use crate::grafo_rs::algoritmo::{subgrafos_conexos, visitar_profundidad, Control, Visitante};
+
+#[cfg(test)]
+mod tests;
+
+///
+/// Vertice del arbol de bloques y articulaciones: un bloque (por su posicion en
+/// [`AnalisisBiconexo::get_bloques`]) o un vertice de articulacion del grafo
+/// 
+pub enum NodoBloque<Vertice>
+where Vertice: VerticeT
+{
+    Bloque(usize),
+    Articulacion(Vertice)
+}
+
+impl<Vertice> Clone for NodoBloque<Vertice>
+where Vertice: VerticeT
+{
+    fn clone(&self) -> Self {
+        match self {
+            NodoBloque::Bloque(i) => NodoBloque::Bloque(*i),
+            NodoBloque::Articulacion(v) => NodoBloque::Articulacion(v.clone())
+        }
+    }
+}
+
+impl<Vertice> PartialEq for NodoBloque<Vertice>
+where Vertice: VerticeT
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (NodoBloque::Bloque(i), NodoBloque::Bloque(j)) => i == j,
+            (NodoBloque::Articulacion(v), NodoBloque::Articulacion(w)) => v == w,
+            _ => false
+        }
+    }
+}
+
+impl<Vertice> Eq for NodoBloque<Vertice>
+where Vertice: VerticeT
+{}
+
+impl<Vertice> Hash for NodoBloque<Vertice>
+where Vertice: VerticeT
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            NodoBloque::Bloque(i) => { 0.hash(state); i.hash(state); },
+            NodoBloque::Articulacion(v) => { 1.hash(state); v.hash(state); }
+        }
+    }
+}
+
+impl<Vertice> Debug for NodoBloque<Vertice>
+where Vertice: VerticeT + Debug
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NodoBloque::Bloque(i) => write!(f, "Bloque({})", i),
+            NodoBloque::Articulacion(v) => write!(f, "Articulacion({:?})", v)
+        }
+    }
+}
+
+impl<Vertice> VerticeT for NodoBloque<Vertice>
+where Vertice: VerticeT
+{}
+
+///
+/// Resultado del analisis de biconexion de un grafo: puentes, vertices de articulacion y bloques
+/// 
+pub struct AnalisisBiconexo<'a, Vertice, Peso>
+where Vertice: VerticeT, Peso: PesoT
+{
+    df: Etiquetado<Vertice>,
+    low: Etiquetado<Vertice>,
+    puentes: Vec<&'a Arista<Vertice, Peso>>,
+    articulaciones: Vec<&'a Vertice>,
+    conjunto_articulaciones: HashSet<&'a Vertice>,
+    bloques: Vec<Grafo<Vertice, Peso>>
+}
+
+impl<'a, Vertice, Peso> AnalisisBiconexo<'a, Vertice, Peso>
+where Vertice: VerticeT, Peso: PesoT
+{
+    ///
+    /// POST: Etiquetado con el orden de descubrimiento de cada vertice en la busqueda en profundidad
+    /// 
+    pub fn get_df(&self) -> &Etiquetado<Vertice>
+    {
+        &self.df
+    }
+
+    ///
+    /// POST: Etiquetado con el menor df alcanzable desde cada vertice bajando por el arbol y usando
+    /// a lo sumo una arista de retroceso
+    /// 
+    pub fn get_low(&self) -> &Etiquetado<Vertice>
+    {
+        &self.low
+    }
+
+    ///
+    /// POST: Vector con las aristas cuya eliminacion aumenta el numero de componentes conexas
+    /// 
+    pub fn get_puentes(&self) -> &Vec<&'a Arista<Vertice, Peso>>
+    {
+        &self.puentes
+    }
+
+    ///
+    /// POST: Vector con los vertices cuya eliminacion aumenta el numero de componentes conexas
+    /// 
+    pub fn get_articulaciones(&self) -> &Vec<&'a Vertice>
+    {
+        &self.articulaciones
+    }
+
+    ///
+    /// POST: Vector con los bloques (componentes biconexas) del grafo. Un vertice sin aristas forma un bloque por si solo
+    /// 
+    pub fn get_bloques(&self) -> &Vec<Grafo<Vertice, Peso>>
+    {
+        &self.bloques
+    }
+
+    ///
+    /// PRE: Arista
+    /// POST: true si la arista es un puente del grafo analizado. false eoc
+    /// 
+    pub fn es_puente(&self, arista: &Arista<Vertice, Peso>) -> bool
+    {
+        self.puentes.iter().any(|x| **x == *arista)
+    }
+
+    ///
+    /// PRE: Vertice
+    /// POST: true si el vertice es de articulacion en el grafo analizado. false eoc
+    /// 
+    pub fn es_articulacion(&self, v: &Vertice) -> bool
+    {
+        self.conjunto_articulaciones.contains(v)
+    }
+
+    ///
+    /// POST: Arbol de bloques y articulaciones de cada componente conexa, con raiz en su primer bloque.
+    /// Cada bloque se une a los vertices de articulacion que contiene
+    /// 
+    pub fn arbol_bloques(&self) -> Bosque<Grafo<NodoBloque<Vertice>, NoPeso>, NodoBloque<Vertice>, NoPeso>
+    {
+        let mut grafo: Grafo<NodoBloque<Vertice>, NoPeso> = Grafo::new();
+        for (i, bloque) in self.bloques.iter().enumerate()
+        {
+            let aristas: Vec<Arista<NodoBloque<Vertice>, NoPeso>> = bloque.get_vertices().into_iter()
+                            .filter(|v| self.conjunto_articulaciones.contains(*v))
+                            .map(|v| Arista::arista_sin_peso(NodoBloque::Bloque(i), NodoBloque::Articulacion(v.clone())))
+                            .collect();
+            match aristas.is_empty() {
+                true => grafo.add_vertices(vec![NodoBloque::Bloque(i)]),
+                false => grafo.add_aristas(aristas)
+            }
+        }
+
+        subgrafos_conexos(&grafo).into_iter()
+            .map(|subgrafo| {
+                let raiz = subgrafo.get_vertices()[0].clone();
+                Arbol::from_grafo(subgrafo, raiz)
+            })
+            .collect()
+    }
+}
+
+///
+/// Visitante auxiliar que calcula los valores low de cada vertice durante la busqueda en profundidad,
+/// apilando las aristas para separar los bloques
+/// 
+struct Lowpoint<'a, Vertice, Peso>
+where Vertice: VerticeT, Peso: PesoT
+{
+    df: HashMap<&'a Vertice, usize>,
+    low: HashMap<&'a Vertice, usize>,
+    padres: HashMap<&'a Vertice, (&'a Vertice, &'a Arista<Vertice, Peso>)>,
+    raiz: Option<&'a Vertice>,
+    hijos_raiz: usize,
+    pila_aristas: Vec<&'a Arista<Vertice, Peso>>,
+    puentes: Vec<&'a Arista<Vertice, Peso>>,
+    articulaciones: Vec<&'a Vertice>,
+    conjunto_articulaciones: HashSet<&'a Vertice>,
+    bloques: Vec<Grafo<Vertice, Peso>>
+}
+
+impl<'a, Vertice, Peso> Lowpoint<'a, Vertice, Peso>
+where Vertice: VerticeT, Peso: PesoT
+{
+    fn new() -> Self
+    {
+        Self {
+            df: HashMap::new(),
+            low: HashMap::new(),
+            padres: HashMap::new(),
+            raiz: None,
+            hijos_raiz: 0,
+            pila_aristas: vec![],
+            puentes: vec![],
+            articulaciones: vec![],
+            conjunto_articulaciones: HashSet::new(),
+            bloques: vec![]
+        }
+    }
+
+    fn marcar_articulacion(&mut self, v: &'a Vertice)
+    {
+        if self.conjunto_articulaciones.insert(v)
+        {
+            self.articulaciones.push(v);
+        }
+    }
+}
+
+impl<'a, Vertice, Peso> Visitante<'a, Vertice, Arista<Vertice, Peso>> for Lowpoint<'a, Vertice, Peso>
+where Vertice: VerticeT, Peso: PesoT
+{
+    fn descubrir_vertice(&mut self, v: &'a Vertice) -> Control
+    {
+        let i = self.df.len();
+        self.df.insert(v, i);
+        self.low.insert(v, i);
+        Control::Continuar
+    }
+
+    fn arista_arbol(&mut self, v: &'a Vertice, arista: &'a Arista<Vertice, Peso>) -> Control
+    {
+        self.padres.insert(arista.other(v).unwrap(), (v, arista));
+        self.pila_aristas.push(arista);
+        if self.raiz == Some(v)
+        {
+            self.hijos_raiz += 1;
+        }
+        Control::Continuar
+    }
+
+    fn arista_retroceso(&mut self, v: &'a Vertice, arista: &'a Arista<Vertice, Peso>) -> Control
+    {
+        let w = arista.other(v).unwrap();
+        // Los lazos no influyen en la biconexion
+        if w != v
+        {
+            let df_w = self.df[w];
+            let low_v = self.low.get_mut(v).unwrap();
+            *low_v = (*low_v).min(df_w);
+            self.pila_aristas.push(arista);
+        }
+        Control::Continuar
+    }
+
+    fn finalizar_vertice(&mut self, v: &'a Vertice) -> Control
+    {
+        match self.padres.get(v).copied() {
+            Some((u, arista_padre)) => {
+                let low_v = self.low[v];
+                let low_u = self.low.get_mut(u).unwrap();
+                *low_u = (*low_u).min(low_v);
+
+                if low_v > self.df[u]
+                {
+                    self.puentes.push(arista_padre);
+                }
+                if low_v >= self.df[u]
+                {
+                    // Las aristas apiladas desde la arista padre forman un bloque
+                    let mut bloque = vec![];
+                    while let Some(arista) = self.pila_aristas.pop()
+                    {
+                        bloque.push(arista.clone());
+                        if std::ptr::eq(arista, arista_padre)
+                        {
+                            break;
+                        }
+                    }
+                    self.bloques.push(Grafo::from_aristas(bloque));
+                    if self.raiz != Some(u)
+                    {
+                        self.marcar_articulacion(u);
+                    }
+                }
+            },
+            None => {
+                // La raiz es de articulacion si tiene al menos dos hijos, y forma un bloque si no tiene ninguno
+                match self.hijos_raiz {
+                    0 => self.bloques.push(Grafo::from_aristas(vec![Arista::vertice(v.clone())])),
+                    1 => {},
+                    _ => self.marcar_articulacion(v)
+                }
+            }
+        }
+        Control::Continuar
+    }
+}
+
+///
+/// PRE: Grafo
+/// POST: Analisis con los puentes, los vertices de articulacion y los bloques del grafo
+/// NOTA: Se calculan los valores low sobre una busqueda en profundidad desde el primer vertice de cada componente,
+/// por lo que el etiquetado df coincide con el de [`arbol_profundidad`](crate::grafo_rs::arbol_profundidad) en la
+/// primera componente. Los lazos se ignoran
+/// 
+pub fn biconexas<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>) -> AnalisisBiconexo<'_, Vertice, Peso>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let mut lowpoint = Lowpoint::new();
+    for v in grafo.get_vertices()
+    {
+        if !lowpoint.df.contains_key(v)
+        {
+            lowpoint.raiz = Some(v);
+            lowpoint.hijos_raiz = 0;
+            visitar_profundidad(grafo, v, &mut lowpoint);
+        }
+    }
+
+    let mut df = Etiquetado::new(Some("df"));
+    let mut low = Etiquetado::new(Some("low"));
+    for v in grafo.get_vertices()
+    {
+        df.add_vertice(v.clone(), lowpoint.df[v] as isize);
+        low.add_vertice(v.clone(), lowpoint.low[v] as isize);
+    }
+    AnalisisBiconexo {
+        df,
+        low,
+        puentes: lowpoint.puentes,
+        articulaciones: lowpoint.articulaciones,
+        conjunto_articulaciones: lowpoint.conjunto_articulaciones,
+        bloques: lowpoint.bloques
+    }
+}
@@ -0,0 +1,119 @@
+use crate::grafo_rs::{Arista, Grafo, GrafoT, NoPeso, arbol_profundidad, num_componentes};
+use super::*;
+
+fn vertices_ordenados(grafo: &Grafo<i32, NoPeso>) -> Vec<i32>
+{
+    let mut vertices: Vec<i32> = grafo.get_vertices().into_iter().copied().collect();
+    vertices.sort();
+    vertices
+}
+
+#[test]
+fn test_puentes_articulaciones_bloques()
+{
+    let g: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(1, 2),
+                                                        Arista::arista_sin_peso(2, 3),
+                                                        Arista::arista_sin_peso(3, 1),
+                                                        Arista::arista_sin_peso(3, 4),
+                                                        Arista::arista_sin_peso(4, 5),
+                                                        Arista::arista_sin_peso(5, 6),
+                                                        Arista::arista_sin_peso(6, 4),
+                                                        Arista::arista_sin_peso(6, 7),
+                                                        Arista::arista_sin_peso(1, 1),
+                                                        Arista::vertice(8),
+                                                        Arista::arista_sin_peso(9, 10)].to_vec());
+    let analisis = biconexas(&g);
+
+    assert_eq!(analisis.get_articulaciones(), &vec![&6, &4, &3]);
+    assert!(analisis.get_puentes().len() == 3);
+    assert!(analisis.es_puente(&Arista::arista_sin_peso(4, 3)));
+    assert!(analisis.es_puente(&Arista::arista_sin_peso(6, 7)));
+    assert!(analisis.es_puente(&Arista::arista_sin_peso(9, 10)));
+    assert!(!analisis.es_puente(&Arista::arista_sin_peso(1, 1)), "Los lazos no son puentes");
+    assert!(!analisis.es_articulacion(&1));
+
+    let bloques: Vec<Vec<i32>> = analisis.get_bloques().iter().map(vertices_ordenados).collect();
+    assert_eq!(bloques, vec![vec![6, 7], vec![4, 5, 6], vec![3, 4], vec![1, 2, 3], vec![8], vec![9, 10]]);
+
+    assert_eq!(analisis.get_df().get_nombre(), Some("df"));
+    assert_eq!(analisis.get_df().buscar_vertice(&4).unwrap().get_valor(), 3);
+    assert_eq!(analisis.get_low().buscar_vertice(&6).unwrap().get_valor(), 3);
+    assert_eq!(analisis.get_low().buscar_vertice(&7).unwrap().get_valor(), 6);
+
+    // Coincide con la numeracion de la busqueda en profundidad
+    let df = arbol_profundidad(&g, &1).unwrap().1;
+    for etiqueta in df.get_datos()
+    {
+        assert_eq!(analisis.get_df().buscar_vertice(etiqueta.get_vertice()).unwrap().get_valor(), etiqueta.get_valor());
+    }
+}
+
+#[test]
+fn test_arbol_bloques()
+{
+    let g: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(1, 2),
+                                                        Arista::arista_sin_peso(2, 3),
+                                                        Arista::arista_sin_peso(3, 1),
+                                                        Arista::arista_sin_peso(3, 4),
+                                                        Arista::arista_sin_peso(4, 5),
+                                                        Arista::arista_sin_peso(5, 6),
+                                                        Arista::arista_sin_peso(6, 4),
+                                                        Arista::arista_sin_peso(6, 7),
+                                                        Arista::vertice(8)].to_vec());
+    let analisis = biconexas(&g);
+    let bosque = analisis.arbol_bloques();
+
+    assert_eq!(bosque.len(), 2);
+    assert_eq!(bosque[0].raiz(), &NodoBloque::Bloque(0));
+    // 4 bloques y 3 articulaciones unidos por 6 aristas
+    assert_eq!(bosque[0].size(), 7);
+    assert_eq!(bosque[0].get_aristas().len(), 6);
+    assert!(bosque[0].get_aristas().contains(&Arista::arista_sin_peso(NodoBloque::Bloque(2), NodoBloque::Articulacion(3))));
+    assert!(bosque[0].get_aristas().contains(&Arista::arista_sin_peso(NodoBloque::Bloque(3), NodoBloque::Articulacion(3))));
+    assert_eq!(bosque[1].get_vertices(), vec![&NodoBloque::Bloque(4)]);
+}
+
+#[test]
+fn test_biconexas_fuerza_bruta()
+{
+    // Rejilla 3x4 con algunas aristas eliminadas y colgantes
+    let mut aristas = vec![];
+    for i in 0..3
+    {
+        for j in 0..4
+        {
+            let v = i * 4 + j;
+            if j < 3 && (i, j) != (1, 1)
+            {
+                aristas.push(Arista::arista_sin_peso(v, v + 1));
+            }
+            if i < 2 && (i, j) != (0, 2) && (i, j) != (1, 2)
+            {
+                aristas.push(Arista::arista_sin_peso(v, v + 4));
+            }
+        }
+    }
+    aristas.push(Arista::arista_sin_peso(11, 20));
+    aristas.push(Arista::arista_sin_peso(20, 21));
+    aristas.push(Arista::arista_sin_peso(0, 22));
+    let g: Grafo<i32, NoPeso> = Grafo::from_aristas(aristas);
+    let analisis = biconexas(&g);
+    let componentes = num_componentes(&g);
+
+    for arista in g.get_aristas()
+    {
+        let mut h = g.clone();
+        h.remove_arista(arista);
+        assert_eq!(analisis.es_puente(arista), num_componentes(&h) > componentes);
+    }
+    for v in g.get_vertices()
+    {
+        let mut h = g.clone();
+        h.remove_vertice(v);
+        assert_eq!(analisis.es_articulacion(v), num_componentes(&h) > componentes, "Vertice {}", v);
+    }
+
+    // Cada arista pertenece a un unico bloque
+    let total: usize = analisis.get_bloques().iter().map(|x| x.get_aristas().len()).sum();
+    assert_eq!(total, g.get_aristas().len());
+}
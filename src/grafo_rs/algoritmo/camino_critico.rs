@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+
+use crate::grafo_rs::{Arbol, AristaT, Diarista, Digrafo, Etiquetado, GrafoT, PesoT, VerticeT};
+use crate::grafo_rs::algoritmo::{orden_topologico, ErrorCamino, ResultadoCamino};
+
+#[cfg(test)]
+mod tests;
+
+///
+/// Resultado del analisis de camino critico (CPM) de un digrafo aciclico, donde cada arco es una actividad
+/// y su Peso, su duracion
+/// 
+pub struct AnalisisCritico<'a, Vertice, Peso>
+where Vertice: VerticeT, Peso: PesoT
+{
+    duracion: isize,
+    inicio_temprano: Etiquetado<Vertice>,
+    inicio_tardio: Etiquetado<Vertice>,
+    holguras: Vec<(&'a Diarista<Vertice, Peso>, isize)>,
+    camino_critico: Vec<&'a Diarista<Vertice, Peso>>
+}
+
+impl<'a, Vertice, Peso> AnalisisCritico<'a, Vertice, Peso>
+where Vertice: VerticeT, Peso: PesoT
+{
+    ///
+    /// POST: Duracion total del proyecto (longitud del camino mas largo)
+    /// 
+    pub fn get_duracion(&self) -> isize
+    {
+        self.duracion
+    }
+
+    ///
+    /// POST: Etiquetado con el instante mas temprano en el que puede alcanzarse cada vertice
+    /// 
+    pub fn get_inicio_temprano(&self) -> &Etiquetado<Vertice>
+    {
+        &self.inicio_temprano
+    }
+
+    ///
+    /// POST: Etiquetado con el instante mas tardio en el que puede alcanzarse cada vertice sin retrasar el proyecto
+    /// 
+    pub fn get_inicio_tardio(&self) -> &Etiquetado<Vertice>
+    {
+        &self.inicio_tardio
+    }
+
+    ///
+    /// POST: Vector con la holgura de cada arco: tiempo que puede retrasarse sin retrasar el proyecto
+    /// 
+    pub fn get_holguras(&self) -> &Vec<(&'a Diarista<Vertice, Peso>, isize)>
+    {
+        &self.holguras
+    }
+
+    ///
+    /// PRE: Arco
+    /// POST: Holgura del arco si esta en el digrafo analizado. None eoc
+    /// 
+    pub fn get_holgura(&self, arco: &Diarista<Vertice, Peso>) -> Option<isize>
+    {
+        self.holguras.iter().find(|x| *x.0 == *arco).map(|x| x.1)
+    }
+
+    ///
+    /// POST: Arcos de un camino critico, en orden de recorrido. Todos tienen holgura nula
+    /// 
+    pub fn get_camino_critico(&self) -> &Vec<&'a Diarista<Vertice, Peso>>
+    {
+        &self.camino_critico
+    }
+}
+
+///
+/// PRE: Digrafo y vertice desde el que se van a calcular los caminos maximos
+/// POST: Terna de Arbol que contiene los caminos mas largos y etiquetado con sus longitudes.
+/// Si el digrafo tiene un ciclo, error con sus arcos
+/// NOTA: Se recorren los vertices en orden topologico, por lo que admite pesos negativos
+/// 
+pub fn arbol_camino_maximo<Vertice, Peso>(digrafo: &Digrafo<Vertice, Peso>, v0: &Vertice)
+    -> ResultadoCamino<Digrafo<Vertice, Peso>, Vertice, Peso>
+where Vertice: VerticeT, Peso: PesoT + Ord
+{
+    let orden = orden_topologico(digrafo)
+                    .map_err(|ciclo| ErrorCamino::Ciclo(ciclo.into_iter().cloned().collect()))?;
+    if !orden.contains(&v0)
+    {
+        return Err(ErrorCamino::VerticeInexistente);
+    }
+
+    let mut distancia_temporal: HashMap<&Vertice, Peso> = HashMap::new();
+    let mut arcos_previos: HashMap<&Vertice, &Diarista<Vertice, Peso>> = HashMap::new();
+    distancia_temporal.insert(v0, Peso::elemento_neutro());
+    for v in orden.iter()
+    {
+        let acarreo = match distancia_temporal.get(v) {
+            Some(d) => d.clone(),
+            None => { continue; }
+        };
+        for arco in digrafo.aristas_accesibles(v)
+        {
+            let w = arco.get_vertices().unwrap().1;
+            let nueva_distancia = acarreo.suma(arco.get_peso().ok_or(ErrorCamino::SinPeso)?);
+            let mejora = match distancia_temporal.get(w) {
+                Some(d) => *d < nueva_distancia,
+                None => true
+            };
+            if mejora
+            {
+                distancia_temporal.insert(w, nueva_distancia);
+                arcos_previos.insert(w, arco);
+            }
+        }
+    }
+
+    let mut arbol = Digrafo::new();
+    let mut distancia = Etiquetado::new(Some("Distancias"));
+    for v in orden.into_iter()
+    {
+        if let Some(d) = distancia_temporal.get(v)
+        {
+            distancia.add_vertice(v.clone(), d.to_isize());
+        }
+        if let Some(arco) = arcos_previos.get(v)
+        {
+            arbol.add_aristas(vec![(*arco).clone()]);
+        }
+    }
+    Ok((Arbol::from_grafo(arbol, v0.clone()), distancia))
+}
+
+///
+/// PRE: Digrafo aciclico cuyos arcos son actividades con duracion
+/// POST: Analisis de camino critico con tiempos tempranos y tardios de cada vertice, holguras de los arcos
+/// y un camino critico. Si el digrafo tiene un ciclo o falta algun peso, el error correspondiente
+/// NOTA: Las duraciones se calculan con `PesoT::to_isize`
+/// 
+pub fn camino_critico<Vertice, Peso>(digrafo: &Digrafo<Vertice, Peso>)
+    -> Result<AnalisisCritico<'_, Vertice, Peso>, ErrorCamino<Diarista<Vertice, Peso>>>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let orden = orden_topologico(digrafo)
+                    .map_err(|ciclo| ErrorCamino::Ciclo(ciclo.into_iter().cloned().collect()))?;
+    let ids: HashMap<&Vertice, usize> = orden.iter().enumerate()
+                                            .map(|(i, v)| (*v, i))
+                                            .collect();
+    // Arcos como (origen, destino, duracion, arco), con los identificadores del orden topologico
+    let mut arcos: Vec<(usize, usize, isize, &Diarista<Vertice, Peso>)> = vec![];
+    for arco in digrafo.get_aristas().iter()
+    {
+        if let Some((u, v)) = arco.get_vertices()
+        {
+            let duracion = arco.get_peso().ok_or(ErrorCamino::SinPeso)?.to_isize();
+            arcos.push((ids[u], ids[v], duracion, arco));
+        }
+    }
+    // Al ordenar por origen, se recorren los arcos respetando el orden topologico
+    arcos.sort_by_key(|x| x.0);
+
+    let n = orden.len();
+    let mut temprano: Vec<isize> = vec![0; n];
+    for (u, v, duracion, _) in arcos.iter()
+    {
+        temprano[*v] = temprano[*v].max(temprano[*u] + duracion);
+    }
+    let duracion_total = temprano.iter().copied().max().unwrap_or(0);
+    let mut tardio: Vec<isize> = vec![duracion_total; n];
+    for (u, v, duracion, _) in arcos.iter().rev()
+    {
+        tardio[*u] = tardio[*u].min(tardio[*v] - duracion);
+    }
+
+    let holguras: Vec<(&Diarista<Vertice, Peso>, isize)> = arcos.iter()
+                        .map(|(u, v, duracion, arco)| (*arco, tardio[*v] - temprano[*u] - duracion))
+                        .collect();
+
+    // Reconstruimos el camino critico hacia atras desde el vertice que termina mas tarde
+    let mut entrantes: Vec<Vec<usize>> = vec![vec![]; n];
+    for (pos, (_, v, _, _)) in arcos.iter().enumerate()
+    {
+        entrantes[*v].push(pos);
+    }
+    let mut camino: Vec<&Diarista<Vertice, Peso>> = vec![];
+    if let Some(mut actual) = (0..n).find(|x| temprano[*x] == duracion_total)
+    {
+        while let Some(pos) = entrantes[actual].iter()
+                    .find(|pos| temprano[arcos[**pos].0] + arcos[**pos].2 == temprano[actual])
+        {
+            camino.push(arcos[*pos].3);
+            actual = arcos[*pos].0;
+        }
+    }
+    camino.reverse();
+
+    let mut inicio_temprano = Etiquetado::new(Some("Inicio temprano"));
+    let mut inicio_tardio = Etiquetado::new(Some("Inicio tardio"));
+    for (id, v) in orden.into_iter().enumerate()
+    {
+        inicio_temprano.add_vertice(v.clone(), temprano[id]);
+        inicio_tardio.add_vertice(v.clone(), tardio[id]);
+    }
+    Ok(AnalisisCritico {
+        duracion: duracion_total,
+        inicio_temprano,
+        inicio_tardio,
+        holguras,
+        camino_critico: camino
+    })
+}
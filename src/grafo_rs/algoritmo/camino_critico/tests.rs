@@ -0,0 +1,64 @@
+use crate::grafo_rs::{AristaT, Diarista, Digrafo, GrafoT};
+use super::*;
+
+fn proyecto() -> Digrafo<char, usize>
+{
+    Digrafo::from_aristas([Diarista::arista('s', 'a', Some(3)),
+                            Diarista::arista('s', 'b', Some(2)),
+                            Diarista::arista('a', 'c', Some(4)),
+                            Diarista::arista('b', 'c', Some(1)),
+                            Diarista::arista('b', 'd', Some(6)),
+                            Diarista::arista('c', 't', Some(2)),
+                            Diarista::arista('d', 't', Some(2))].to_vec())
+}
+
+#[test]
+fn test_arbol_camino_maximo()
+{
+    let dg = proyecto();
+    let (arbol, distancias) = arbol_camino_maximo(&dg, &'s').expect("El digrafo es aciclico");
+    assert_eq!(distancias.buscar_vertice(&'c').unwrap().get_valor(), 7);
+    assert_eq!(distancias.buscar_vertice(&'t').unwrap().get_valor(), 10);
+    assert!(arbol.get_aristas().contains(&Diarista::arista('d', 't', Some(2))));
+    assert!(arbol.get_aristas().contains(&Diarista::arista('a', 'c', Some(4))));
+
+    let (_, distancias) = arbol_camino_maximo(&dg, &'a').expect("El digrafo es aciclico");
+    assert!(distancias.buscar_vertice(&'b').is_none(), "b no es alcanzable desde a");
+    assert!(matches!(arbol_camino_maximo(&dg, &'z'), Err(ErrorCamino::VerticeInexistente)));
+}
+
+#[test]
+fn test_camino_critico()
+{
+    let dg = proyecto();
+    let analisis = camino_critico(&dg).expect("El digrafo es aciclico");
+    assert_eq!(analisis.get_duracion(), 10);
+
+    assert_eq!(analisis.get_inicio_temprano().buscar_vertice(&'c').unwrap().get_valor(), 7);
+    assert_eq!(analisis.get_inicio_tardio().buscar_vertice(&'c').unwrap().get_valor(), 8);
+    assert_eq!(analisis.get_inicio_temprano().buscar_vertice(&'d').unwrap().get_valor(), 8);
+    assert_eq!(analisis.get_inicio_tardio().buscar_vertice(&'a').unwrap().get_valor(), 4);
+
+    assert_eq!(analisis.get_holgura(&Diarista::arista('b', 'c', Some(1))), Some(5));
+    assert_eq!(analisis.get_holgura(&Diarista::arista('a', 'c', Some(4))), Some(1));
+    assert_eq!(analisis.get_holgura(&Diarista::arista('b', 'd', Some(6))), Some(0));
+    assert_eq!(analisis.get_holguras().len(), 7);
+
+    let camino = analisis.get_camino_critico();
+    let esperado = [Diarista::arista('s', 'b', Some(2)), Diarista::arista('b', 'd', Some(6)), Diarista::arista('d', 't', Some(2))];
+    assert!(*camino == esperado.iter().collect::<Vec<&Diarista<char, usize>>>());
+    assert!(camino.iter().all(|x| analisis.get_holgura(x) == Some(0)));
+}
+
+#[test]
+fn test_camino_critico_errores()
+{
+    let mut dg = proyecto();
+    dg.add_aristas(vec![Diarista::arista('t', 's', Some(1))]);
+    assert!(matches!(camino_critico(&dg), Err(ErrorCamino::Ciclo(_))));
+    assert!(matches!(arbol_camino_maximo(&dg, &'s'), Err(ErrorCamino::Ciclo(_))));
+
+    let dg: Digrafo<char, usize> = Digrafo::from_aristas([Diarista::arista('s', 'a', Some(3)),
+                                                            Diarista::arista_sin_peso('a', 'b')].to_vec());
+    assert!(matches!(camino_critico(&dg), Err(ErrorCamino::SinPeso)));
+}
@@ -0,0 +1,320 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::Debug;
+
+use crate::grafo_rs::{Arbol, AristaT, Etiquetado, GrafoT, PesoT, VerticeT};
+
+pub mod matriz;
+pub use matriz::MatrizDistancias;
+
+#[cfg(test)]
+mod tests;
+
+///
+/// Errores que pueden producirse al calcular caminos minimos o maximos
+/// 
+pub enum ErrorCamino<Arista> {
+    ///
+    /// El vertice de partida no pertenece al grafo
+    /// 
+    VerticeInexistente,
+    ///
+    /// Una arista alcanzable no tiene peso
+    /// 
+    SinPeso,
+    ///
+    /// Hay un ciclo de peso negativo alcanzable. Contiene sus aristas en orden de recorrido
+    /// 
+    CicloNegativo(Vec<Arista>),
+    ///
+    /// El algoritmo requiere un digrafo aciclico y hay un ciclo. Contiene sus aristas en orden de recorrido
+    /// 
+    Ciclo(Vec<Arista>)
+}
+
+///
+/// Resultado de los algoritmos de caminos minimos: Arbol de caminos minimos y etiquetado con las longitudes
+/// 
+pub type ResultadoCamino<Graf, Vertice, Peso> = Result<(Arbol<Graf, Vertice, Peso>, Etiquetado<Vertice>),
+                                                ErrorCamino<<Graf as GrafoT<Vertice, Peso>>::Arista>>;
+
+impl<Arista> Debug for ErrorCamino<Arista>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::VerticeInexistente => write!(f, "VerticeInexistente"),
+            Self::SinPeso => write!(f, "SinPeso"),
+            Self::CicloNegativo(ciclo) => write!(f, "CicloNegativo({} aristas)", ciclo.len()),
+            Self::Ciclo(ciclo) => write!(f, "Ciclo({} aristas)", ciclo.len())
+        }
+    }
+}
+
+///
+/// Funcion auxiliar. Devuelve los arcos recorribles del grafo como ternas (origen, destino, arista),
+/// usando los identificadores dados. Las aristas no dirigidas generan dos arcos
+/// 
+fn arcos<'a, Graf, Vertice, Peso>(grafo: &'a Graf, ids: &HashMap<&Vertice, usize>) -> Vec<(usize, usize, &'a Graf::Arista)>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
+{
+    let mut arcos = vec![];
+    for arista in grafo.get_aristas().iter()
+    {
+        if let Some((v, w)) = arista.get_vertices()
+        {
+            if arista.es_accesible(v)
+            {
+                arcos.push((ids[v], ids[w], arista));
+            }
+            if v != w && arista.es_accesible(w)
+            {
+                arcos.push((ids[w], ids[v], arista));
+            }
+        }
+    }
+    arcos
+}
+
+///
+/// Funcion auxiliar. Relaja los arcos dados hasta n veces, siendo n el numero de vertices,
+/// partiendo de las distancias temporales proporcionadas
+/// POST: Si hay un ciclo negativo alcanzable, error con sus aristas. Si un arco alcanzable no tiene peso, error.
+/// Eoc, las distancias y los predecesores quedan actualizados
+/// 
+fn relajar_arcos<'a, Arista, Vertice, Peso>(arcos: &[(usize, usize, &'a Arista)], distancia_temporal: &mut [Option<Peso>],
+                                            previos: &mut [Option<(usize, &'a Arista)>]) -> Result<(), ErrorCamino<Arista>>
+where Arista: AristaT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT + Ord
+{
+    // Si en la ultima iteracion sigue habiendo cambios, hay un ciclo negativo
+    let mut ultimo_relajado = None;
+    for _ in 0..distancia_temporal.len()
+    {
+        ultimo_relajado = None;
+        for (origen, destino, arista) in arcos.iter()
+        {
+            let acarreo = match &distancia_temporal[*origen] {
+                Some(d) => d,
+                None => { continue; }
+            };
+            let peso = arista.get_peso().ok_or(ErrorCamino::SinPeso)?;
+            let nueva_distancia = acarreo.suma(peso);
+            let mejora = match &distancia_temporal[*destino] {
+                Some(d) => *d > nueva_distancia,
+                None => true
+            };
+            if mejora
+            {
+                distancia_temporal[*destino] = Some(nueva_distancia);
+                previos[*destino] = Some((*origen, *arista));
+                ultimo_relajado = Some(*destino);
+            }
+        }
+        if ultimo_relajado.is_none()
+        {
+            return Ok(());
+        }
+    }
+
+    let mut id = ultimo_relajado.unwrap();
+    // Retrocedemos n veces para asegurar que estamos dentro del ciclo
+    for _ in 0..distancia_temporal.len()
+    {
+        id = previos[id].unwrap().0;
+    }
+    let mut ciclo = vec![];
+    let mut actual = id;
+    loop {
+        let (anterior, arista) = previos[actual].unwrap();
+        ciclo.push(arista.clone());
+        actual = anterior;
+        if actual == id
+        {
+            break;
+        }
+    }
+    ciclo.reverse();
+    Err(ErrorCamino::CicloNegativo(ciclo))
+}
+
+///
+/// PRE: Grafo y vertice desde el que se van a calcular los caminos minimos
+/// POST: Terna de Arbol que contiene los caminos minimos y etiquetado con las longitudes.
+/// Si no existen, el error correspondiente. En caso de haber un ciclo negativo alcanzable, se devuelven sus aristas
+/// NOTA: Implementacion del algoritmo de Bellman-Ford. Admite pesos negativos. Una arista no dirigida
+/// de peso negativo es un ciclo negativo
+/// 
+pub fn bellman_ford<Graf, Vertice, Peso>(grafo: &Graf, v0: &Vertice) -> ResultadoCamino<Graf, Vertice, Peso>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT + Ord
+{
+    let vertices = grafo.get_vertices();
+    let ids: HashMap<&Vertice, usize> = vertices.iter().enumerate()
+                                            .map(|(i, v)| (*v, i))
+                                            .collect();
+    let id0 = *ids.get(v0).ok_or(ErrorCamino::VerticeInexistente)?;
+    let arcos = arcos(grafo, &ids);
+
+    let mut distancia_temporal: Vec<Option<Peso>> = vec![None; vertices.len()];
+    // Para cada vertice, el vertice y la arista por la que se alcanza
+    let mut previos: Vec<Option<(usize, &Graf::Arista)>> = vec![None; vertices.len()];
+    distancia_temporal[id0] = Some(Peso::elemento_neutro());
+    relajar_arcos(&arcos, &mut distancia_temporal, &mut previos)?;
+
+    let mut arbol = Graf::new();
+    let mut distancia = Etiquetado::new(Some("Distancias"));
+    for (id, d) in distancia_temporal.iter().enumerate()
+    {
+        if let Some(d) = d
+        {
+            distancia.add_vertice(vertices[id].clone(), d.to_isize());
+        }
+        if let Some((_, arista)) = previos[id]
+        {
+            arbol.add_aristas(vec![arista.clone()]);
+        }
+    }
+    Ok((Arbol::from_grafo(arbol, v0.clone()), distancia))
+}
+
+///
+/// PRE: Grafo
+/// POST: Matriz con las distancias minimas entre todos los pares de vertices. Si hay un ciclo negativo,
+/// error con sus aristas. Si falta algun peso, error
+/// NOTA: Implementacion del algoritmo de Floyd-Warshall, O(n^3). Adecuado para grafos densos
+/// 
+pub fn floyd_warshall<Graf, Vertice, Peso>(grafo: &Graf) -> Result<MatrizDistancias<Vertice>, ErrorCamino<Graf::Arista>>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT + Ord
+{
+    let vertices = grafo.get_vertices();
+    let n = vertices.len();
+    let ids: HashMap<&Vertice, usize> = vertices.iter().enumerate()
+                                            .map(|(i, v)| (*v, i))
+                                            .collect();
+
+    let mut distancias: Vec<Vec<Option<Peso>>> = vec![vec![None; n]; n];
+    let mut previos: Vec<Vec<Option<usize>>> = vec![vec![None; n]; n];
+    for (i, fila) in distancias.iter_mut().enumerate()
+    {
+        fila[i] = Some(Peso::elemento_neutro());
+    }
+    for (origen, destino, arista) in arcos(grafo, &ids).into_iter()
+    {
+        let peso = arista.get_peso().ok_or(ErrorCamino::SinPeso)?;
+        let mejora = match &distancias[origen][destino] {
+            Some(d) => d > peso,
+            None => true
+        };
+        if mejora
+        {
+            distancias[origen][destino] = Some(peso.clone());
+            previos[origen][destino] = Some(origen);
+        }
+    }
+
+    for k in 0..n
+    {
+        for i in 0..n
+        {
+            let d_ik = match &distancias[i][k] {
+                Some(d) => d.clone(),
+                None => { continue; }
+            };
+            for j in 0..n
+            {
+                let nueva_distancia = match &distancias[k][j] {
+                    Some(d) => d_ik.suma(d),
+                    None => { continue; }
+                };
+                let mejora = match &distancias[i][j] {
+                    Some(d) => *d > nueva_distancia,
+                    None => true
+                };
+                if mejora
+                {
+                    distancias[i][j] = Some(nueva_distancia);
+                    previos[i][j] = previos[k][j];
+                }
+            }
+        }
+    }
+
+    // Si algun vertice tiene distancia negativa a si mismo, esta en un ciclo negativo
+    for (i, fila) in distancias.iter().enumerate()
+    {
+        if fila[i].as_ref().unwrap().es_negativo()
+        {
+            return match bellman_ford(grafo, vertices[i]) {
+                Err(e) => Err(e),
+                Ok(_) => unreachable!("Bellman-Ford debe encontrar el ciclo negativo")
+            };
+        }
+    }
+
+    let distancias = distancias.into_iter()
+                        .map(|fila| fila.into_iter().map(|d| d.map(|x| x.to_isize())).collect())
+                        .collect();
+    Ok(MatrizDistancias::new(vertices.into_iter().cloned().collect(), distancias, previos))
+}
+
+///
+/// PRE: Grafo
+/// POST: Matriz con las distancias minimas entre todos los pares de vertices. Si hay un ciclo negativo,
+/// error con sus aristas. Si falta algun peso, error
+/// NOTA: Implementacion del algoritmo de Johnson: se calculan potenciales con Bellman-Ford, se reponderan los arcos
+/// para que no sean negativos y se aplica Dijkstra desde cada vertice. O(n m log n). Adecuado para grafos dispersos.
+/// Los pesos reponderados se calculan con `PesoT::to_isize`
+/// 
+pub fn johnson<Graf, Vertice, Peso>(grafo: &Graf) -> Result<MatrizDistancias<Vertice>, ErrorCamino<Graf::Arista>>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT + Ord
+{
+    let vertices = grafo.get_vertices();
+    let n = vertices.len();
+    let ids: HashMap<&Vertice, usize> = vertices.iter().enumerate()
+                                            .map(|(i, v)| (*v, i))
+                                            .collect();
+    let arcos = arcos(grafo, &ids);
+
+    // Equivale a añadir un vertice auxiliar unido a todos con peso nulo
+    let mut potenciales: Vec<Option<Peso>> = vec![Some(Peso::elemento_neutro()); n];
+    let mut previos_potenciales: Vec<Option<(usize, &Graf::Arista)>> = vec![None; n];
+    relajar_arcos(&arcos, &mut potenciales, &mut previos_potenciales)?;
+    let potenciales: Vec<isize> = potenciales.into_iter().map(|x| x.unwrap().to_isize()).collect();
+
+    // Lista de adyacencia con los pesos reponderados, que no son negativos
+    let mut adyacencia: Vec<Vec<(usize, isize)>> = vec![vec![]; n];
+    for (origen, destino, arista) in arcos.into_iter()
+    {
+        let peso = arista.get_peso().unwrap().to_isize();
+        adyacencia[origen].push((destino, peso + potenciales[origen] - potenciales[destino]));
+    }
+
+    let mut distancias: Vec<Vec<Option<isize>>> = vec![vec![None; n]; n];
+    let mut previos: Vec<Vec<Option<usize>>> = vec![vec![None; n]; n];
+    for origen in 0..n
+    {
+        let mut vertices_visitados = vec![false; n];
+        let mut distancia_temporal: Vec<Option<isize>> = vec![None; n];
+        let mut cola = BinaryHeap::new();
+        distancia_temporal[origen] = Some(0);
+        cola.push(Reverse((0, origen)));
+        while let Some(Reverse((acarreo, id))) = cola.pop()
+        {
+            if vertices_visitados[id]
+            {
+                continue;
+            }
+            vertices_visitados[id] = true;
+            distancias[origen][id] = Some(acarreo - potenciales[origen] + potenciales[id]);
+            for (otro, peso) in adyacencia[id].iter()
+            {
+                let nueva_distancia = acarreo + peso;
+                if !vertices_visitados[*otro] && distancia_temporal[*otro].is_none_or(|d| d > nueva_distancia)
+                {
+                    distancia_temporal[*otro] = Some(nueva_distancia);
+                    previos[origen][*otro] = Some(id);
+                    cola.push(Reverse((nueva_distancia, *otro)));
+                }
+            }
+        }
+    }
+    Ok(MatrizDistancias::new(vertices.into_iter().cloned().collect(), distancias, previos))
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use crate::grafo_rs::{Etiquetado, VerticeT};
+
+///
+/// Estructura que almacena las distancias y los predecesores de los caminos minimos entre
+/// todos los pares de vertices de un grafo
+/// 
+pub struct MatrizDistancias<Vertice>
+where Vertice: VerticeT
+{
+    vertices: Vec<Vertice>,
+    ids: HashMap<Vertice, usize>,
+    /*
+     * distancias[i][j]: longitud del camino minimo de i a j, si existe
+     * previos[i][j]: vertice anterior a j en el camino minimo de i a j, si existe
+     */
+    distancias: Vec<Vec<Option<isize>>>,
+    previos: Vec<Vec<Option<usize>>>
+}
+
+impl<Vertice> MatrizDistancias<Vertice>
+where Vertice: VerticeT
+{
+    ///
+    /// PRE: Vertices, matriz de distancias y matriz de predecesores indexadas segun los vertices
+    /// POST: Objeto MatrizDistancias
+    /// 
+    pub(crate) fn new(vertices: Vec<Vertice>, distancias: Vec<Vec<Option<isize>>>, previos: Vec<Vec<Option<usize>>>) -> Self
+    {
+        let ids = vertices.iter().enumerate()
+                    .map(|(i, v)| (v.clone(), i))
+                    .collect();
+        Self {
+            vertices,
+            ids,
+            distancias,
+            previos
+        }
+    }
+
+    ///
+    /// POST: Vector con referencias a los vertices de la matriz
+    /// 
+    pub fn get_vertices(&self) -> Vec<&Vertice>
+    {
+        self.vertices.iter().collect()
+    }
+
+    ///
+    /// PRE: Par de vertices
+    /// POST: Longitud del camino minimo de u a v. None si no existe camino o algun vertice no esta en la matriz
+    /// 
+    pub fn distancia(&self, u: &Vertice, v: &Vertice) -> Option<isize>
+    {
+        self.distancias[*self.ids.get(u)?][*self.ids.get(v)?]
+    }
+
+    ///
+    /// PRE: Par de vertices
+    /// POST: Vertice anterior a v en el camino minimo de u a v. None si no existe o si u = v
+    /// 
+    pub fn previo(&self, u: &Vertice, v: &Vertice) -> Option<&Vertice>
+    {
+        let id = self.previos[*self.ids.get(u)?][*self.ids.get(v)?]?;
+        Some(&self.vertices[id])
+    }
+
+    ///
+    /// PRE: Par de vertices
+    /// POST: Vertices del camino minimo de u a v, incluyendo ambos extremos. None si no existe camino
+    /// 
+    pub fn camino(&self, u: &Vertice, v: &Vertice) -> Option<Vec<&Vertice>>
+    {
+        let id_u = *self.ids.get(u)?;
+        let mut id = *self.ids.get(v)?;
+        self.distancias[id_u][id]?;
+
+        let mut camino = vec![&self.vertices[id]];
+        while id != id_u
+        {
+            id = self.previos[id_u][id]?;
+            camino.push(&self.vertices[id]);
+        }
+        camino.reverse();
+        Some(camino)
+    }
+
+    ///
+    /// PRE: Vertice
+    /// POST: Etiquetado con las distancias desde u a los vertices alcanzables. None si u no esta en la matriz
+    /// 
+    pub fn etiquetado(&self, u: &Vertice) -> Option<Etiquetado<Vertice>>
+    {
+        let id_u = *self.ids.get(u)?;
+        let mut distancia = Etiquetado::new(Some("Distancias"));
+        for (id, d) in self.distancias[id_u].iter().enumerate()
+        {
+            if let Some(d) = d
+            {
+                distancia.add_vertice(self.vertices[id].clone(), *d);
+            }
+        }
+        Some(distancia)
+    }
+}
+
+impl<Vertice> Clone for MatrizDistancias<Vertice>
+where Vertice: VerticeT
+{
+    fn clone(&self) -> Self {
+        Self {
+            vertices: self.vertices.clone(),
+            ids: self.ids.clone(),
+            distancias: self.distancias.clone(),
+            previos: self.previos.clone()
+        }
+    }
+}
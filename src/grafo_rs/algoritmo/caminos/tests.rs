@@ -0,0 +1,174 @@
+use crate::grafo_rs::{Arista, Diarista, AristaT, Grafo, Digrafo, GrafoT, arbol_camino_minimo};
+use super::*;
+
+#[test]
+fn test_bellman_ford_pesos_negativos()
+{
+    let dg: Digrafo<char, isize> = Digrafo::from_aristas([Diarista::arista('s', 't', Some(6)),
+                                                            Diarista::arista('s', 'y', Some(7)),
+                                                            Diarista::arista('t', 'x', Some(5)),
+                                                            Diarista::arista('t', 'y', Some(8)),
+                                                            Diarista::arista('t', 'z', Some(-4)),
+                                                            Diarista::arista('x', 't', Some(-2)),
+                                                            Diarista::arista('y', 'x', Some(-3)),
+                                                            Diarista::arista('y', 'z', Some(9)),
+                                                            Diarista::arista('z', 's', Some(2)),
+                                                            Diarista::arista('z', 'x', Some(7))].to_vec());
+    let (arbol, distancias) = bellman_ford(&dg, &'s').expect("No hay ciclos negativos");
+    assert_eq!(distancias.buscar_vertice(&'s').unwrap().get_valor(), 0);
+    assert_eq!(distancias.buscar_vertice(&'t').unwrap().get_valor(), 2);
+    assert_eq!(distancias.buscar_vertice(&'x').unwrap().get_valor(), 4);
+    assert_eq!(distancias.buscar_vertice(&'y').unwrap().get_valor(), 7);
+    assert_eq!(distancias.buscar_vertice(&'z').unwrap().get_valor(), -2);
+
+    let arbol = arbol.into_grafo();
+    assert_eq!(arbol.get_aristas().len(), 4);
+    assert!(arbol.get_aristas().contains(&Diarista::arista('x', 't', Some(-2))));
+    assert!(arbol.get_aristas().contains(&Diarista::arista('y', 'x', Some(-3))));
+}
+
+#[test]
+fn test_bellman_ford_coincide_con_dijkstra()
+{
+    let g: Grafo<char, usize> = Grafo::from_aristas([Arista::arista('A', 'B', Some(3)),
+                                                            Arista::arista('A', 'C', Some(1)),
+                                                            Arista::arista('B', 'C', Some(7)),
+                                                            Arista::arista('B', 'E', Some(1)),
+                                                            Arista::arista('B', 'D', Some(5)),
+                                                            Arista::arista('C', 'D', Some(2)),
+                                                            Arista::arista('D', 'E', Some(7)),
+                                                            Arista::vertice('F')].to_vec());
+    let dijkstra = arbol_camino_minimo(&g, &'C').expect("El arbol debe existir").1;
+    let bellman = bellman_ford(&g, &'C').expect("No hay pesos negativos").1;
+    for etiqueta in dijkstra.get_datos().iter()
+    {
+        assert_eq!(bellman.buscar_vertice(etiqueta.get_vertice()).unwrap().get_valor(), etiqueta.get_valor());
+    }
+    assert!(bellman.buscar_vertice(&'F').is_none(), "F no es alcanzable");
+}
+
+#[test]
+fn test_bellman_ford_ciclo_negativo()
+{
+    let dg: Digrafo<i32, i32> = Digrafo::from_aristas([Diarista::arista(1, 2, Some(1)),
+                                                        Diarista::arista(2, 3, Some(2)),
+                                                        Diarista::arista(3, 4, Some(-4)),
+                                                        Diarista::arista(4, 2, Some(1)),
+                                                        Diarista::arista(4, 5, Some(1))].to_vec());
+    match bellman_ford(&dg, &1) {
+        Err(ErrorCamino::CicloNegativo(ciclo)) => {
+            assert_eq!(ciclo.len(), 3);
+            assert_eq!(Diarista::sumatorio_pesos(&ciclo), -1);
+            // Las aristas deben encadenarse
+            for i in 0..ciclo.len()
+            {
+                let siguiente = &ciclo[(i + 1) % ciclo.len()];
+                assert_eq!(ciclo[i].get_vertices().unwrap().1, siguiente.get_vertices().unwrap().0);
+            }
+        },
+        _ => panic!("Debe detectarse el ciclo negativo")
+    }
+
+    // El ciclo no es alcanzable desde 5
+    assert!(bellman_ford(&dg, &5).is_ok());
+}
+
+#[test]
+fn test_bellman_ford_errores()
+{
+    let g: Grafo<i32, isize> = Grafo::from_aristas([Arista::arista(1, 2, Some(3)),
+                                                            Arista::arista(2, 3, Some(-1))].to_vec());
+    assert!(matches!(bellman_ford(&g, &10), Err(ErrorCamino::VerticeInexistente)));
+    assert!(matches!(bellman_ford(&g, &1), Err(ErrorCamino::CicloNegativo(_))),
+            "Una arista no dirigida negativa es un ciclo negativo");
+
+    let g: Grafo<i32, isize> = Grafo::from_aristas([Arista::arista(1, 2, Some(3)),
+                                                            Arista::arista(2, 3, None)].to_vec());
+    assert!(matches!(bellman_ford(&g, &1), Err(ErrorCamino::SinPeso)));
+}
+
+#[test]
+fn test_floyd_warshall()
+{
+    let dg: Digrafo<i32, i32> = Digrafo::from_aristas([Diarista::arista(1, 2, Some(3)),
+                                                        Diarista::arista(1, 3, Some(8)),
+                                                        Diarista::arista(1, 5, Some(-4)),
+                                                        Diarista::arista(2, 4, Some(1)),
+                                                        Diarista::arista(2, 5, Some(7)),
+                                                        Diarista::arista(3, 2, Some(4)),
+                                                        Diarista::arista(4, 1, Some(2)),
+                                                        Diarista::arista(4, 3, Some(-5)),
+                                                        Diarista::arista(5, 4, Some(6)),
+                                                        Diarista::vertice(6)].to_vec());
+    let matriz = floyd_warshall(&dg).expect("No hay ciclos negativos");
+    assert_eq!(matriz.distancia(&1, &2), Some(1));
+    assert_eq!(matriz.distancia(&3, &1), Some(7));
+    assert_eq!(matriz.distancia(&5, &3), Some(1));
+    assert_eq!(matriz.distancia(&2, &2), Some(0));
+    assert_eq!(matriz.distancia(&1, &6), None, "6 no es alcanzable");
+    assert_eq!(matriz.distancia(&1, &10), None, "10 no esta en el grafo");
+
+    assert_eq!(matriz.camino(&1, &2), Some(vec![&1, &5, &4, &3, &2]));
+    assert_eq!(matriz.camino(&4, &4), Some(vec![&4]));
+    assert_eq!(matriz.previo(&1, &2), Some(&3));
+    assert!(matriz.camino(&6, &1).is_none());
+
+    let etiquetado = matriz.etiquetado(&1).expect("1 esta en el grafo");
+    assert_eq!(etiquetado.get_datos().len(), 5);
+    assert_eq!(etiquetado.min().unwrap().get_valor(), -4);
+}
+
+#[test]
+fn test_johnson_coincide_con_floyd_warshall()
+{
+    let dg: Digrafo<i32, i32> = Digrafo::from_aristas([Diarista::arista(1, 2, Some(3)),
+                                                        Diarista::arista(1, 3, Some(8)),
+                                                        Diarista::arista(1, 5, Some(-4)),
+                                                        Diarista::arista(2, 4, Some(1)),
+                                                        Diarista::arista(2, 5, Some(7)),
+                                                        Diarista::arista(3, 2, Some(4)),
+                                                        Diarista::arista(4, 1, Some(2)),
+                                                        Diarista::arista(4, 3, Some(-5)),
+                                                        Diarista::arista(5, 4, Some(6)),
+                                                        Diarista::vertice(6)].to_vec());
+    let floyd = floyd_warshall(&dg).expect("No hay ciclos negativos");
+    let matriz = johnson(&dg).expect("No hay ciclos negativos");
+    for u in dg.get_vertices()
+    {
+        for v in dg.get_vertices()
+        {
+            assert_eq!(floyd.distancia(u, v), matriz.distancia(u, v));
+            assert_eq!(floyd.camino(u, v), matriz.camino(u, v));
+        }
+    }
+
+    let g: Grafo<char, usize> = Grafo::from_aristas([Arista::arista('A', 'B', Some(3)),
+                                                            Arista::arista('A', 'C', Some(1)),
+                                                            Arista::arista('B', 'C', Some(7)),
+                                                            Arista::arista('B', 'E', Some(1)),
+                                                            Arista::arista('B', 'D', Some(5)),
+                                                            Arista::arista('C', 'D', Some(2)),
+                                                            Arista::arista('D', 'E', Some(7))].to_vec());
+    let matriz = johnson(&g).expect("No hay pesos negativos");
+    assert_eq!(matriz.distancia(&'C', &'E'), Some(5));
+    assert_eq!(matriz.distancia(&'E', &'C'), Some(5));
+    assert_eq!(matriz.camino(&'E', &'C'), Some(vec![&'E', &'B', &'A', &'C']));
+}
+
+#[test]
+fn test_todos_los_pares_ciclo_negativo()
+{
+    let dg: Digrafo<i32, i32> = Digrafo::from_aristas([Diarista::arista(1, 2, Some(1)),
+                                                        Diarista::arista(2, 3, Some(2)),
+                                                        Diarista::arista(3, 4, Some(-4)),
+                                                        Diarista::arista(4, 2, Some(1)),
+                                                        Diarista::arista(5, 1, Some(1))].to_vec());
+    match floyd_warshall(&dg) {
+        Err(ErrorCamino::CicloNegativo(ciclo)) => assert_eq!(Diarista::sumatorio_pesos(&ciclo), -1),
+        _ => panic!("Floyd-Warshall debe detectar el ciclo negativo")
+    }
+    match johnson(&dg) {
+        Err(ErrorCamino::CicloNegativo(ciclo)) => assert_eq!(Diarista::sumatorio_pesos(&ciclo), -1),
+        _ => panic!("Johnson debe detectar el ciclo negativo")
+    }
+}
@@ -0,0 +1,273 @@
+use crate::grafo_rs::{Arista, AristaT, Diarista, Digrafo, Grafo, GrafoT, PesoT, VerticeT};
+use crate::grafo_rs::algoritmo::{floyd_warshall, MatrizDistancias};
+use crate::grafo_rs::algoritmo::edmonds::parejas_peso_maximo;
+use crate::grafo_rs::algoritmo::euler::hierholzer;
+
+#[cfg(test)]
+mod tests;
+
+///
+/// Recorrido cerrado del problema del cartero chino: aristas en orden, repetidas si es necesario, y peso total
+/// 
+pub type RecorridoCartero<'a, Arista, Peso> = (Vec<&'a Arista>, Peso);
+
+///
+/// Funcion auxiliar. Comprueba que todas las aristas tengan peso no negativo
+/// 
+fn pesos_validos<Graf, Vertice, Peso>(grafo: &Graf) -> bool
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
+{
+    grafo.get_aristas().iter()
+        .filter(|x| x.get_vertices().is_some())
+        .all(|x| x.get_peso().is_some_and(|p| !p.es_negativo()))
+}
+
+///
+/// Funcion auxiliar. Aristas de menor peso de un camino minimo de u a v, en orden
+/// 
+fn aristas_camino<'a, Graf, Vertice, Peso>(grafo: &'a Graf, matriz: &MatrizDistancias<Vertice>, u: &Vertice, v: &Vertice) -> Vec<&'a Graf::Arista>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT + Ord
+{
+    let camino = matriz.camino(u, v).unwrap();
+    camino.windows(2)
+        .map(|par| grafo.aristas_accesibles(par[0]).into_iter()
+                        .filter(|x| x.other(par[0]) == Some(par[1]))
+                        .min_by(|x, y| x.get_peso().cmp(&y.get_peso()))
+                        .unwrap())
+        .collect()
+}
+
+///
+/// Funcion auxiliar. Recorre la lista de aristas ampliada y calcula su peso total. None si no puede recorrerse entera
+/// 
+fn cerrar_recorrido<'a, Arista, Vertice, Peso>(aristas: Vec<&'a Arista>) -> Option<RecorridoCartero<'a, Arista, Peso>>
+where Arista: AristaT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
+{
+    let recorrido = match aristas.first() {
+        Some(arista) => hierholzer(&aristas, arista.get_vertices().unwrap().0),
+        None => vec![]
+    };
+    if recorrido.len() != aristas.len()
+    {
+        return None;
+    }
+    let peso = Arista::sumatorio_pesos(&recorrido.iter().map(|x| (*x).clone()).collect());
+    Some((recorrido, peso))
+}
+
+///
+/// Funcion auxiliar. Emparejamiento perfecto de peso minimo entre los elementos segun sus distancias.
+/// None si no existe (algun par necesario no tiene distancia)
+/// NOTA: Emparejamiento de peso maximo de Edmonds con pesos cota - distancia. O(k³). Como la cota supera el doble
+/// de la suma de todas las distancias, cualquier emparejamiento perfecto pesa mas que uno con menos aristas
+/// 
+pub(crate) fn emparejamiento_minimo(distancias: &[Vec<Option<isize>>]) -> Option<Vec<(usize, usize)>>
+{
+    let k = distancias.len();
+    let cota = 2 * distancias.iter().flatten().flatten().map(|x| x.abs()).sum::<isize>() + 1;
+    let aristas: Vec<(usize, usize, isize)> = (0..k)
+                        .flat_map(|i| (i + 1..k).filter_map(move |j| distancias[i][j].map(|d| (i, j, cota - d))))
+                        .collect();
+    let parejas = parejas_peso_maximo(k, aristas);
+    let mut pares = vec![];
+    for (i, pareja) in parejas.into_iter().enumerate()
+    {
+        let j = pareja?;
+        if i < j
+        {
+            pares.push((i, j));
+        }
+    }
+    Some(pares)
+}
+
+///
+/// Funcion auxiliar. Flujo de coste minimo del problema de transporte entre ofertas y demandas de igual suma,
+/// con capacidades ilimitadas. Devuelve las unidades enviadas de cada oferta a cada demanda. None si no pueden
+/// enviarse todas
+/// NOTA: Caminos minimos sucesivos con Bellman-Ford sobre la red residual
+/// 
+fn transporte_minimo(ofertas: &[usize], demandas: &[usize], costes: &[Vec<Option<isize>>]) -> Option<Vec<Vec<usize>>>
+{
+    let (a, b) = (ofertas.len(), demandas.len());
+    let (fuente, sumidero) = (a + b, a + b + 1);
+    let n = a + b + 2;
+    // Arcos residuales (origen, destino, capacidad, coste). El arco inverso de i es i ^ 1
+    let mut arcos: Vec<(usize, usize, usize, isize)> = vec![];
+    let agregar = |arcos: &mut Vec<(usize, usize, usize, isize)>, u: usize, v: usize, capacidad: usize, coste: isize| {
+        arcos.push((u, v, capacidad, coste));
+        arcos.push((v, u, 0, -coste));
+    };
+    let total: usize = ofertas.iter().sum();
+    for (i, oferta) in ofertas.iter().enumerate()
+    {
+        agregar(&mut arcos, fuente, i, *oferta, 0);
+    }
+    for (j, demanda) in demandas.iter().enumerate()
+    {
+        agregar(&mut arcos, a + j, sumidero, *demanda, 0);
+    }
+    let inicio_transporte = arcos.len();
+    for (i, fila) in costes.iter().enumerate()
+    {
+        for (j, coste) in fila.iter().enumerate()
+        {
+            if let Some(coste) = coste
+            {
+                agregar(&mut arcos, i, a + j, total, *coste);
+            }
+        }
+    }
+
+    let mut enviado = 0;
+    while enviado < total
+    {
+        // Camino de coste minimo de la fuente al sumidero en la red residual
+        let mut distancia: Vec<Option<isize>> = vec![None; n];
+        let mut previo: Vec<Option<usize>> = vec![None; n];
+        distancia[fuente] = Some(0);
+        for _ in 0..n
+        {
+            let mut cambio = false;
+            for (pos, (u, v, capacidad, coste)) in arcos.iter().enumerate()
+            {
+                if *capacidad == 0
+                {
+                    continue;
+                }
+                if let Some(d) = distancia[*u]
+                {
+                    if distancia[*v].is_none_or(|actual| d + coste < actual)
+                    {
+                        distancia[*v] = Some(d + coste);
+                        previo[*v] = Some(pos);
+                        cambio = true;
+                    }
+                }
+            }
+            if !cambio
+            {
+                break;
+            }
+        }
+        distancia[sumidero]?;
+
+        // Capacidad del camino y actualizacion de la red residual
+        let mut camino = vec![];
+        let mut v = sumidero;
+        while let Some(pos) = previo[v]
+        {
+            camino.push(pos);
+            v = arcos[pos].0;
+        }
+        let incremento = camino.iter().map(|pos| arcos[*pos].2).min().unwrap();
+        for pos in camino
+        {
+            arcos[pos].2 -= incremento;
+            arcos[pos ^ 1].2 += incremento;
+        }
+        enviado += incremento;
+    }
+
+    let mut flujo = vec![vec![0; b]; a];
+    for pos in (inicio_transporte..arcos.len()).step_by(2)
+    {
+        let (u, v, _, _) = arcos[pos];
+        flujo[u][v - a] += arcos[pos ^ 1].2;
+    }
+    Some(flujo)
+}
+
+///
+/// PRE: Grafo con pesos no negativos
+/// POST: Recorrido cerrado de peso minimo que pasa al menos una vez por cada arista, junto con su peso total.
+/// None si faltan pesos, hay pesos negativos o las aristas no estan en una misma componente conexa
+/// NOTA: Se duplican los caminos minimos de un emparejamiento perfecto de peso minimo entre los vertices de grado
+/// impar y se construye un circuito euleriano. El emparejamiento es cubico en el numero de vertices impares
+/// 
+pub fn cartero_chino<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>) -> Option<RecorridoCartero<'_, Arista<Vertice, Peso>, Peso>>
+where Vertice: VerticeT, Peso: PesoT + Ord
+{
+    if !pesos_validos(grafo)
+    {
+        return None;
+    }
+    let mut aristas: Vec<&Arista<Vertice, Peso>> = grafo.get_aristas().iter()
+                        .filter(|x| x.get_vertices().is_some())
+                        .collect();
+    let impares: Vec<&Vertice> = grafo.get_vertices().into_iter()
+                        .filter(|v| grafo.grado(v).unwrap() % 2 == 1)
+                        .collect();
+
+    if !impares.is_empty()
+    {
+        let matriz = floyd_warshall(grafo).ok()?;
+        let distancias: Vec<Vec<Option<isize>>> = impares.iter()
+                        .map(|u| impares.iter().map(|v| matriz.distancia(u, v)).collect())
+                        .collect();
+        for (i, j) in emparejamiento_minimo(&distancias)?
+        {
+            aristas.append(&mut aristas_camino(grafo, &matriz, impares[i], impares[j]));
+        }
+    }
+    cerrar_recorrido(aristas)
+}
+
+///
+/// PRE: Digrafo con pesos no negativos
+/// POST: Recorrido cerrado de peso minimo que pasa al menos una vez por cada arco respetando su sentido,
+/// junto con su peso total. None si faltan pesos, hay pesos negativos o el digrafo no es fuertemente conexo
+/// NOTA: Se duplican caminos minimos desde los vertices con mas arcos de entrada que de salida hasta los que
+/// tienen mas de salida, segun un flujo de coste minimo, y se construye un circuito euleriano
+/// 
+pub fn cartero_chino_dirigido<Vertice, Peso>(digrafo: &Digrafo<Vertice, Peso>) -> Option<RecorridoCartero<'_, Diarista<Vertice, Peso>, Peso>>
+where Vertice: VerticeT, Peso: PesoT + Ord
+{
+    if !pesos_validos(digrafo)
+    {
+        return None;
+    }
+    let mut aristas: Vec<&Diarista<Vertice, Peso>> = digrafo.get_aristas().iter()
+                        .filter(|x| x.get_vertices().is_some())
+                        .collect();
+
+    // Vertices con exceso de entrada (ofertas) y con exceso de salida (demandas)
+    let mut origenes = vec![];
+    let mut destinos = vec![];
+    for v in digrafo.get_vertices()
+    {
+        let entrada = digrafo.grado_entrada(v).unwrap();
+        let salida = digrafo.grado_salida(v).unwrap();
+        if entrada > salida
+        {
+            origenes.push((v, entrada - salida));
+        }
+        else if salida > entrada
+        {
+            destinos.push((v, salida - entrada));
+        }
+    }
+
+    if !origenes.is_empty()
+    {
+        let matriz = floyd_warshall(digrafo).ok()?;
+        let costes: Vec<Vec<Option<isize>>> = origenes.iter()
+                        .map(|(u, _)| destinos.iter().map(|(v, _)| matriz.distancia(u, v)).collect())
+                        .collect();
+        let ofertas: Vec<usize> = origenes.iter().map(|x| x.1).collect();
+        let demandas: Vec<usize> = destinos.iter().map(|x| x.1).collect();
+        let flujo = transporte_minimo(&ofertas, &demandas, &costes)?;
+        for (i, fila) in flujo.iter().enumerate()
+        {
+            for (j, unidades) in fila.iter().enumerate().filter(|x| *x.1 > 0)
+            {
+                let camino = aristas_camino(digrafo, &matriz, origenes[i].0, destinos[j].0);
+                for _ in 0..*unidades
+                {
+                    aristas.extend(camino.iter());
+                }
+            }
+        }
+    }
+    cerrar_recorrido(aristas)
+}
@@ -0,0 +1,140 @@
+use crate::grafo_rs::{Arista, Diarista, AristaT, Grafo, Digrafo, GrafoT};
+use super::*;
+
+///
+/// Recorre las aristas desde el vertice dado. Devuelve el vertice final si todas pueden recorrerse
+/// 
+fn recorrer<A>(inicio: i32, recorrido: &[&A]) -> Option<i32>
+where A: AristaT<i32, isize>
+{
+    let mut actual = inicio;
+    for arista in recorrido
+    {
+        if !arista.es_accesible(&actual)
+        {
+            return None;
+        }
+        actual = *arista.other(&actual).unwrap();
+    }
+    Some(actual)
+}
+
+///
+/// Comprueba que el recorrido sea cerrado y pase por todas las aristas del grafo. Devuelve su longitud
+/// 
+fn comprobar_recorrido<Graf>(grafo: &Graf, recorrido: &[&Graf::Arista]) -> usize
+where Graf: GrafoT<i32, isize>
+{
+    let (v, w) = recorrido[0].get_vertices().unwrap();
+    assert!([*v, *w].into_iter().any(|inicio| recorrer(inicio, recorrido) == Some(inicio)),
+            "El recorrido debe ser cerrado");
+    for arista in grafo.get_aristas()
+    {
+        assert!(recorrido.contains(&arista), "Todas las aristas deben recorrerse");
+    }
+    recorrido.len()
+}
+
+#[test]
+fn test_cartero_chino()
+{
+    // Todos los vertices tienen grado impar. El mejor emparejamiento es {1-4, 2-3} con peso 3
+    let g: Grafo<i32, isize> = Grafo::from_aristas([Arista::arista(1, 2, Some(3)),
+                                                        Arista::arista(2, 3, Some(2)),
+                                                        Arista::arista(3, 4, Some(4)),
+                                                        Arista::arista(4, 1, Some(1)),
+                                                        Arista::arista(1, 3, Some(5)),
+                                                        Arista::arista(2, 4, Some(6))].to_vec());
+    let (recorrido, peso) = cartero_chino(&g).expect("Existe recorrido");
+    assert_eq!(peso, 24);
+    assert_eq!(comprobar_recorrido(&g, &recorrido), 8);
+
+    // Un grafo euleriano no necesita repetir aristas
+    let g: Grafo<i32, isize> = Grafo::from_aristas([Arista::arista(1, 2, Some(3)),
+                                                        Arista::arista(2, 3, Some(2)),
+                                                        Arista::arista(3, 1, Some(4)),
+                                                        Arista::arista(3, 3, Some(1))].to_vec());
+    let (recorrido, peso) = cartero_chino(&g).expect("Existe recorrido");
+    assert_eq!(peso, 10);
+    assert_eq!(comprobar_recorrido(&g, &recorrido), 4);
+}
+
+#[test]
+fn test_cartero_chino_invalido()
+{
+    let negativo: Grafo<i32, isize> = Grafo::from_aristas([Arista::arista(1, 2, Some(-1)),
+                                                            Arista::arista(2, 3, Some(2))].to_vec());
+    assert!(cartero_chino(&negativo).is_none());
+
+    let sin_peso: Grafo<i32, isize> = Grafo::from_aristas([Arista::arista(1, 2, Some(1)),
+                                                            Arista::arista(2, 3, None)].to_vec());
+    assert!(cartero_chino(&sin_peso).is_none());
+
+    let disconexo: Grafo<i32, isize> = Grafo::from_aristas([Arista::arista(1, 2, Some(1)),
+                                                            Arista::arista(3, 4, Some(1))].to_vec());
+    assert!(cartero_chino(&disconexo).is_none());
+}
+
+#[test]
+fn test_cartero_chino_dirigido()
+{
+    let digrafo: Digrafo<i32, isize> = Digrafo::from_aristas([Diarista::arista(1, 2, Some(1)),
+                                                                Diarista::arista(2, 3, Some(1)),
+                                                                Diarista::arista(3, 1, Some(1)),
+                                                                Diarista::arista(1, 3, Some(2))].to_vec());
+    let (recorrido, peso) = cartero_chino_dirigido(&digrafo).expect("Existe recorrido");
+    assert_eq!(peso, 6);
+    assert_eq!(comprobar_recorrido(&digrafo, &recorrido), 5);
+
+    // Se necesitan dos copias del camino de 4 a 1
+    let digrafo: Digrafo<i32, isize> = Digrafo::from_aristas([Diarista::arista(1, 2, Some(1)),
+                                                                Diarista::arista(1, 3, Some(1)),
+                                                                Diarista::arista(1, 5, Some(1)),
+                                                                Diarista::arista(2, 4, Some(1)),
+                                                                Diarista::arista(3, 4, Some(1)),
+                                                                Diarista::arista(5, 4, Some(1)),
+                                                                Diarista::arista(4, 1, Some(2))].to_vec());
+    let (recorrido, peso) = cartero_chino_dirigido(&digrafo).expect("Existe recorrido");
+    assert_eq!(peso, 12);
+    assert_eq!(comprobar_recorrido(&digrafo, &recorrido), 9);
+
+    let no_fuerte: Digrafo<i32, isize> = Digrafo::from_aristas([Diarista::arista(1, 2, Some(1)),
+                                                                Diarista::arista(2, 3, Some(1))].to_vec());
+    assert!(cartero_chino_dirigido(&no_fuerte).is_none());
+}
+
+#[test]
+fn test_cartero_chino_muchos_impares()
+{
+    // Estrella con 70 hojas: todas tienen grado impar y cada arista se recorre dos veces
+    let g: Grafo<i32, isize> = Grafo::from_aristas((1..=70).map(|i| Arista::arista(0, i, Some((i % 3 + 1) as isize))).collect());
+    let (recorrido, peso) = cartero_chino(&g).expect("Existe recorrido");
+    assert_eq!(peso, 280);
+    assert_eq!(comprobar_recorrido(&g, &recorrido), 140);
+}
+
+#[test]
+fn test_auxiliares()
+{
+    let distancias = vec![vec![None, Some(3), Some(5), Some(1)],
+                          vec![Some(3), None, Some(2), Some(4)],
+                          vec![Some(5), Some(2), None, Some(4)],
+                          vec![Some(1), Some(4), Some(4), None]];
+    let mut pares = emparejamiento_minimo(&distancias).expect("Existe emparejamiento");
+    pares.sort();
+    assert_eq!(pares, vec![(0, 3), (1, 2)]);
+
+    // Puntos en una recta: el optimo empareja cada punto par con el siguiente
+    let recta: Vec<Vec<Option<isize>>> = (0..80)
+                    .map(|i: isize| (0..80).map(|j: isize| Some((i - j).abs())).collect())
+                    .collect();
+    let mut pares = emparejamiento_minimo(&recta).expect("Existe emparejamiento");
+    pares.sort();
+    assert_eq!(pares, (0..40).map(|i| (2 * i, 2 * i + 1)).collect::<Vec<(usize, usize)>>());
+    assert!(emparejamiento_minimo(&[vec![None, None], vec![None, None]]).is_none());
+
+    let flujo = transporte_minimo(&[2, 1], &[1, 2], &[vec![Some(1), Some(3)], vec![Some(1), None]])
+                    .expect("Se puede transportar");
+    assert_eq!(flujo, vec![vec![0, 2], vec![1, 0]]);
+    assert!(transporte_minimo(&[1], &[1], &[vec![None]]).is_none());
+}
@@ -0,0 +1,181 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::grafo_rs::{Arista, AristaT, Grafo, GrafoT, PesoT, VerticeT};
+use crate::grafo_rs::algoritmo::recorrido_anchura;
+
+#[cfg(test)]
+mod tests;
+
+///
+/// Estructura auxiliar con un bosque generador en anchura: arista por la que se llega a cada vertice y su profundidad
+/// 
+struct BosqueGenerador<'a, Vertice, Peso>
+where Vertice: VerticeT, Peso: PesoT
+{
+    ids: HashMap<&'a Vertice, usize>,
+    padres: Vec<Option<(usize, &'a Arista<Vertice, Peso>)>>,
+    profundidades: Vec<usize>
+}
+
+impl<'a, Vertice, Peso> BosqueGenerador<'a, Vertice, Peso>
+where Vertice: VerticeT, Peso: PesoT
+{
+    fn new(grafo: &'a Grafo<Vertice, Peso>) -> Self
+    {
+        let vertices = grafo.get_vertices();
+        let ids: HashMap<&Vertice, usize> = vertices.iter().enumerate()
+                                                .map(|(i, v)| (*v, i))
+                                                .collect();
+        let mut padres = vec![None; vertices.len()];
+        let mut profundidades = vec![0; vertices.len()];
+        let mut visitados = vec![false; vertices.len()];
+        for raiz in vertices.iter()
+        {
+            if visitados[ids[raiz]]
+            {
+                continue;
+            }
+            for (v, arista) in recorrido_anchura(grafo, raiz)
+            {
+                let id = ids[v];
+                visitados[id] = true;
+                if let Some(arista) = arista
+                {
+                    let padre = ids[arista.other(v).unwrap()];
+                    padres[id] = Some((padre, arista));
+                    profundidades[id] = profundidades[padre] + 1;
+                }
+            }
+        }
+        Self { ids, padres, profundidades }
+    }
+
+    ///
+    /// POST: true si la arista pertenece al bosque
+    /// 
+    fn es_rama(&self, arista: &Arista<Vertice, Peso>) -> bool
+    {
+        match arista.get_vertices() {
+            Some((v, w)) => [v, w].into_iter()
+                                .any(|x| self.padres[self.ids[x]].is_some_and(|(_, a)| std::ptr::eq(a, arista))),
+            None => true
+        }
+    }
+
+    ///
+    /// PRE: Arista del grafo que no pertenece al bosque
+    /// POST: Ciclo que cierra la arista con el bosque, empezando por ella y en orden de recorrido
+    /// 
+    fn ciclo(&self, arista: &'a Arista<Vertice, Peso>) -> Vec<&'a Arista<Vertice, Peso>>
+    {
+        let (v, w) = arista.get_vertices().unwrap();
+        let (mut x, mut y) = (self.ids[v], self.ids[w]);
+        // Aristas desde v y desde w hasta su antecesor comun
+        let mut desde_v = vec![];
+        let mut desde_w = vec![];
+        while x != y
+        {
+            if self.profundidades[x] >= self.profundidades[y]
+            {
+                let (padre, rama) = self.padres[x].unwrap();
+                desde_v.push(rama);
+                x = padre;
+            }
+            else
+            {
+                let (padre, rama) = self.padres[y].unwrap();
+                desde_w.push(rama);
+                y = padre;
+            }
+        }
+        let mut ciclo = vec![arista];
+        ciclo.append(&mut desde_w);
+        desde_v.reverse();
+        ciclo.append(&mut desde_v);
+        ciclo
+    }
+}
+
+///
+/// PRE: Grafo
+/// POST: Aristas de un ciclo del grafo en orden de recorrido, si existe. None si el grafo es un bosque
+/// NOTA: Un lazo es un ciclo de una arista y dos aristas paralelas forman un ciclo de dos
+/// 
+pub fn buscar_ciclo<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>) -> Option<Vec<&Arista<Vertice, Peso>>>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let bosque = BosqueGenerador::new(grafo);
+    let arista = grafo.get_aristas().iter().find(|x| !bosque.es_rama(x))?;
+    Some(bosque.ciclo(arista))
+}
+
+///
+/// PRE: Grafo
+/// POST: Cintura del grafo: longitud del ciclo mas corto. None si el grafo es un bosque
+/// NOTA: Busqueda en anchura desde cada vertice. O(n m). Los lazos tienen longitud 1 y las aristas paralelas 2
+/// 
+pub fn cintura<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>) -> Option<usize>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let vertices = grafo.get_vertices();
+    let ids: HashMap<&Vertice, usize> = vertices.iter().enumerate()
+                                            .map(|(i, v)| (*v, i))
+                                            .collect();
+    let mut cintura: Option<usize> = None;
+    for (origen, raiz) in vertices.iter().enumerate()
+    {
+        let mut distancias: Vec<Option<usize>> = vec![None; vertices.len()];
+        let mut padres: Vec<Option<&Arista<Vertice, Peso>>> = vec![None; vertices.len()];
+        distancias[origen] = Some(0);
+        let mut cola = VecDeque::from([*raiz]);
+        while let Some(v) = cola.pop_front()
+        {
+            let id = ids[v];
+            let d = distancias[id].unwrap();
+            // Ningun ciclo por explorar puede mejorar la cintura actual
+            if cintura.is_some_and(|c| 2 * d + 1 >= c)
+            {
+                break;
+            }
+            for arista in grafo.aristas_accesibles(v)
+            {
+                if padres[id].is_some_and(|a| std::ptr::eq(a, arista))
+                {
+                    continue;
+                }
+                let w = ids[arista.other(v).unwrap()];
+                let longitud = match distancias[w] {
+                    Some(dw) => d + dw + 1,
+                    None => {
+                        distancias[w] = Some(d + 1);
+                        padres[w] = Some(arista);
+                        cola.push_back(vertices[w]);
+                        continue;
+                    }
+                };
+                if cintura.is_none_or(|c| longitud < c)
+                {
+                    cintura = Some(longitud);
+                }
+            }
+        }
+    }
+    cintura
+}
+
+///
+/// PRE: Grafo
+/// POST: Base de ciclos fundamentales: un ciclo por cada arista que no pertenece a un bosque generador,
+/// formado por ella y el camino del bosque entre sus extremos. Cada ciclo empieza por dicha arista
+/// NOTA: El bosque generador es el de busqueda en anchura desde el primer vertice de cada componente.
+/// La base tiene m - n + c ciclos, siendo c el numero de componentes conexas
+/// 
+pub fn base_ciclos<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>) -> Vec<Vec<&Arista<Vertice, Peso>>>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let bosque = BosqueGenerador::new(grafo);
+    grafo.get_aristas().iter()
+        .filter(|x| !bosque.es_rama(x))
+        .map(|x| bosque.ciclo(x))
+        .collect()
+}
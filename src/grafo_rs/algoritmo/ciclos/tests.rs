@@ -0,0 +1,123 @@
+use crate::grafo_rs::{Arista, AristaT, Grafo, GrafoT, NoPeso, num_componentes};
+use super::*;
+
+///
+/// Comprueba que las aristas formen un ciclo: un recorrido cerrado sin aristas ni vertices repetidos
+/// 
+fn es_ciclo<Peso>(ciclo: &[&Arista<i32, Peso>]) -> bool
+where Peso: PesoT
+{
+    let (v, w) = ciclo[0].get_vertices().unwrap();
+    [*v, *w].into_iter().any(|inicio| {
+        let mut actual = inicio;
+        let mut visitados = vec![];
+        for arista in ciclo
+        {
+            if visitados.contains(&actual) || !arista.es_accesible(&actual)
+            {
+                return false;
+            }
+            visitados.push(actual);
+            actual = *arista.other(&actual).unwrap();
+        }
+        actual == inicio
+    })
+}
+
+fn petersen() -> Grafo<i32, NoPeso>
+{
+    let mut aristas = vec![];
+    for i in 0..5
+    {
+        aristas.push(Arista::arista_sin_peso(i, (i + 1) % 5));
+        aristas.push(Arista::arista_sin_peso(i, i + 5));
+        aristas.push(Arista::arista_sin_peso(i + 5, (i + 2) % 5 + 5));
+    }
+    Grafo::from_aristas(aristas)
+}
+
+#[test]
+fn test_bosque_sin_ciclos()
+{
+    let bosque: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(1, 2),
+                                                            Arista::arista_sin_peso(2, 3),
+                                                            Arista::arista_sin_peso(2, 4),
+                                                            Arista::arista_sin_peso(5, 6),
+                                                            Arista::vertice(7)].to_vec());
+    assert!(buscar_ciclo(&bosque).is_none());
+    assert_eq!(cintura(&bosque), None);
+    assert!(base_ciclos(&bosque).is_empty());
+}
+
+#[test]
+fn test_buscar_ciclo()
+{
+    let g: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(1, 2),
+                                                        Arista::arista_sin_peso(2, 3),
+                                                        Arista::arista_sin_peso(3, 4),
+                                                        Arista::arista_sin_peso(4, 5),
+                                                        Arista::arista_sin_peso(5, 2),
+                                                        Arista::arista_sin_peso(5, 6)].to_vec());
+    let ciclo = buscar_ciclo(&g).expect("Hay un ciclo");
+    assert_eq!(ciclo.len(), 4);
+    assert!(es_ciclo(&ciclo));
+
+    let lazo: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(1, 2),
+                                                          Arista::arista_sin_peso(2, 2)].to_vec());
+    let ciclo = buscar_ciclo(&lazo).expect("Un lazo es un ciclo");
+    assert!(ciclo.len() == 1 && ciclo[0] == &Arista::arista_sin_peso(2, 2));
+}
+
+#[test]
+fn test_cintura()
+{
+    assert_eq!(cintura(&petersen()), Some(5));
+
+    // Cubo: cintura 4
+    let mut aristas = vec![];
+    for v in 0..8
+    {
+        for bit in [1, 2, 4]
+        {
+            if v & bit == 0
+            {
+                aristas.push(Arista::arista_sin_peso(v, v | bit));
+            }
+        }
+    }
+    let mut cubo: Grafo<i32, NoPeso> = Grafo::from_aristas(aristas);
+    assert_eq!(cintura(&cubo), Some(4));
+    cubo.add_aristas(vec![Arista::arista_sin_peso(0, 3)]);
+    assert_eq!(cintura(&cubo), Some(3));
+    cubo.add_aristas(vec![Arista::arista_sin_peso(7, 7)]);
+    assert_eq!(cintura(&cubo), Some(1));
+
+    let paralelas: Grafo<i32, isize> = Grafo::from_aristas([Arista::arista(1, 2, Some(1)),
+                                                              Arista::arista(1, 2, Some(2)),
+                                                              Arista::arista(2, 3, Some(1))].to_vec());
+    assert_eq!(cintura(&paralelas), Some(2));
+    assert!(es_ciclo(&buscar_ciclo(&paralelas).unwrap()));
+}
+
+#[test]
+fn test_base_ciclos()
+{
+    let mut g = petersen();
+    g.add_aristas(vec![Arista::arista_sin_peso(20, 21),
+                       Arista::arista_sin_peso(21, 22),
+                       Arista::arista_sin_peso(22, 20),
+                       Arista::vertice(30)]);
+    let base = base_ciclos(&g);
+    let m = g.get_aristas().iter().filter(|x| x.get_vertices().is_some()).count();
+    assert_eq!(base.len(), m - g.size() + num_componentes(&g));
+
+    // Cada ciclo es el unico que contiene a su primera arista
+    for (i, ciclo) in base.iter().enumerate()
+    {
+        assert!(es_ciclo(ciclo));
+        for (j, otro) in base.iter().enumerate()
+        {
+            assert_eq!(otro.iter().any(|x| std::ptr::eq(*x, ciclo[0])), i == j);
+        }
+    }
+}
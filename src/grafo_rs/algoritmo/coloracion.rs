@@ -0,0 +1,315 @@
+use std::collections::HashMap;
+
+use crate::grafo_rs::{AristaT, Etiquetado, Grafo, GrafoT, PesoT, VerticeT};
+
+#[cfg(test)]
+mod tests;
+
+///
+/// Orden en el que la coloracion voraz asigna colores a los vertices
+/// 
+pub enum OrdenColoracion
+{
+    /// Orden de los vertices en el grafo
+    Natural,
+    /// De mayor a menor grado
+    GradoDecreciente,
+    /// Se elimina repetidamente el vertice de menor grado y se colorea en orden inverso de eliminacion
+    MenorUltimo
+}
+
+///
+/// Funcion auxiliar. Vertices del grafo y lista de vecinos distintos de cada uno. None si hay algun lazo
+/// 
+fn adyacencia<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>) -> Option<(Vec<&Vertice>, Vec<Vec<usize>>)>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let vertices = grafo.get_vertices();
+    let ids: HashMap<&Vertice, usize> = vertices.iter().enumerate()
+                                            .map(|(i, v)| (*v, i))
+                                            .collect();
+    let mut vecinos: Vec<Vec<usize>> = vec![vec![]; vertices.len()];
+    for arista in grafo.get_aristas()
+    {
+        if let Some((v, w)) = arista.get_vertices()
+        {
+            let (i, j) = (ids[v], ids[w]);
+            if i == j
+            {
+                return None;
+            }
+            if !vecinos[i].contains(&j)
+            {
+                vecinos[i].push(j);
+                vecinos[j].push(i);
+            }
+        }
+    }
+    Some((vertices, vecinos))
+}
+
+///
+/// Funcion auxiliar. Etiquetado de colores a partir del color de cada vertice
+/// 
+fn etiquetado_colores<Vertice>(vertices: &[&Vertice], colores: &[usize]) -> Etiquetado<Vertice>
+where Vertice: VerticeT
+{
+    let mut etiquetado = Etiquetado::new(Some("Color"));
+    for (v, color) in vertices.iter().zip(colores.iter())
+    {
+        etiquetado.add_vertice((*v).clone(), *color as isize);
+    }
+    etiquetado
+}
+
+///
+/// Funcion auxiliar. Menor color no usado por los vecinos ya coloreados
+/// 
+fn menor_color_libre(vecinos: &[usize], colores: &[Option<usize>]) -> usize
+{
+    let mut usados = vec![false; vecinos.len() + 1];
+    for color in vecinos.iter().filter_map(|w| colores[*w])
+    {
+        if color < usados.len()
+        {
+            usados[color] = true;
+        }
+    }
+    usados.iter().position(|x| !x).unwrap()
+}
+
+///
+/// Funcion auxiliar. Orden de menor ultimo: inverso del orden en el que se eliminan los vertices de menor grado
+/// 
+fn orden_menor_ultimo(vecinos: &[Vec<usize>]) -> Vec<usize>
+{
+    let n = vecinos.len();
+    let mut grados: Vec<usize> = vecinos.iter().map(|x| x.len()).collect();
+    let mut eliminados = vec![false; n];
+    let mut orden = vec![];
+    for _ in 0..n
+    {
+        let v = (0..n).filter(|v| !eliminados[*v]).min_by_key(|v| grados[*v]).unwrap();
+        eliminados[v] = true;
+        orden.push(v);
+        for w in vecinos[v].iter()
+        {
+            grados[*w] -= 1;
+        }
+    }
+    orden.reverse();
+    orden
+}
+
+///
+/// PRE: Grafo sin lazos
+/// POST: Etiquetado "Color" con colores desde 0: cada vertice, en el orden dado, recibe el menor color que no usan
+/// sus vecinos. None si hay algun lazo
+/// 
+pub fn coloracion_voraz<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>, orden: OrdenColoracion) -> Option<Etiquetado<Vertice>>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let (vertices, vecinos) = adyacencia(grafo)?;
+    let n = vertices.len();
+    let orden: Vec<usize> = match orden {
+        OrdenColoracion::Natural => (0..n).collect(),
+        OrdenColoracion::GradoDecreciente => {
+            let mut orden: Vec<usize> = (0..n).collect();
+            orden.sort_by_key(|v| std::cmp::Reverse(vecinos[*v].len()));
+            orden
+        },
+        OrdenColoracion::MenorUltimo => orden_menor_ultimo(&vecinos)
+    };
+
+    let mut colores: Vec<Option<usize>> = vec![None; n];
+    for v in orden
+    {
+        colores[v] = Some(menor_color_libre(&vecinos[v], &colores));
+    }
+    let colores: Vec<usize> = colores.into_iter().map(|x| x.unwrap()).collect();
+    Some(etiquetado_colores(&vertices, &colores))
+}
+
+///
+/// Funcion auxiliar. Coloracion DSatur sobre la lista de vecinos
+/// 
+fn colores_dsatur(vecinos: &[Vec<usize>]) -> Vec<usize>
+{
+    let n = vecinos.len();
+    let mut colores: Vec<Option<usize>> = vec![None; n];
+    // Colores distintos entre los vecinos de cada vertice
+    let mut saturacion: Vec<Vec<bool>> = vec![vec![]; n];
+    let mut grado_saturacion = vec![0; n];
+    for _ in 0..n
+    {
+        let v = (0..n).filter(|v| colores[*v].is_none())
+                    .max_by_key(|v| (grado_saturacion[*v], vecinos[*v].len(), std::cmp::Reverse(*v)))
+                    .unwrap();
+        let color = menor_color_libre(&vecinos[v], &colores);
+        colores[v] = Some(color);
+        for w in vecinos[v].iter()
+        {
+            if saturacion[*w].len() <= color
+            {
+                saturacion[*w].resize(color + 1, false);
+            }
+            if !saturacion[*w][color]
+            {
+                saturacion[*w][color] = true;
+                grado_saturacion[*w] += 1;
+            }
+        }
+    }
+    colores.into_iter().map(|x| x.unwrap()).collect()
+}
+
+///
+/// PRE: Grafo sin lazos
+/// POST: Etiquetado "Color" con colores desde 0 segun DSatur: se colorea primero el vertice con mas colores distintos
+/// entre sus vecinos y, en caso de empate, el de mayor grado. None si hay algun lazo
+/// 
+pub fn dsatur<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>) -> Option<Etiquetado<Vertice>>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let (vertices, vecinos) = adyacencia(grafo)?;
+    Some(etiquetado_colores(&vertices, &colores_dsatur(&vecinos)))
+}
+
+///
+/// Estructura auxiliar para la ramificacion y poda del numero cromatico
+/// 
+struct Ramificacion<'v>
+{
+    vecinos: &'v [Vec<usize>],
+    colores: Vec<Option<usize>>,
+    mejor: Vec<usize>,
+    num_mejor: usize,
+    cota_inferior: usize
+}
+
+impl<'v> Ramificacion<'v>
+{
+    ///
+    /// POST: Colorea los vertices restantes usando a lo sumo num_colores + nuevos colores por debajo de la mejor
+    /// solucion. true si se alcanza la cota inferior
+    /// 
+    fn colorear(&mut self, num_colores: usize, restantes: usize) -> bool
+    {
+        if restantes == 0
+        {
+            self.num_mejor = num_colores;
+            self.mejor = self.colores.iter().map(|x| x.unwrap()).collect();
+            return num_colores == self.cota_inferior;
+        }
+
+        // Vertice sin colorear de mayor saturacion
+        let mut elegido = None;
+        let mut mejor_clave = (0, 0);
+        for v in (0..self.vecinos.len()).filter(|v| self.colores[*v].is_none())
+        {
+            let mut usados: Vec<usize> = self.vecinos[v].iter().filter_map(|w| self.colores[*w]).collect();
+            usados.sort();
+            usados.dedup();
+            let clave = (usados.len(), self.vecinos[v].len());
+            if elegido.is_none() || clave > mejor_clave
+            {
+                elegido = Some((v, usados));
+                mejor_clave = clave;
+            }
+        }
+        let (v, usados) = elegido.unwrap();
+
+        // Colores existentes libres y, si no empeora la mejor solucion, un color nuevo
+        let limite = num_colores.min(self.num_mejor - 1);
+        for color in (0..=limite).filter(|c| usados.binary_search(c).is_err())
+        {
+            let nuevos = num_colores.max(color + 1);
+            if nuevos >= self.num_mejor
+            {
+                continue;
+            }
+            self.colores[v] = Some(color);
+            if self.colorear(nuevos, restantes - 1)
+            {
+                return true;
+            }
+        }
+        self.colores[v] = None;
+        false
+    }
+}
+
+///
+/// Funcion auxiliar. Tamaño de una clique obtenida de forma voraz, como cota inferior del numero cromatico
+/// 
+fn clique_voraz(vecinos: &[Vec<usize>]) -> usize
+{
+    let mut mejor = 0;
+    for inicio in 0..vecinos.len()
+    {
+        let mut clique = vec![inicio];
+        let mut candidatos: Vec<usize> = vecinos[inicio].clone();
+        candidatos.sort_by_key(|v| std::cmp::Reverse(vecinos[*v].len()));
+        for v in candidatos
+        {
+            if clique.iter().all(|u| vecinos[v].contains(u))
+            {
+                clique.push(v);
+            }
+        }
+        mejor = mejor.max(clique.len());
+    }
+    mejor
+}
+
+///
+/// PRE: Grafo sin lazos
+/// POST: Numero cromatico y Etiquetado "Color" con una coloracion optima. None si hay algun lazo
+/// NOTA: Ramificacion y poda partiendo de DSatur y acotada por una clique voraz. Coste exponencial: adecuado
+/// para grafos pequeños
+/// 
+pub fn numero_cromatico<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>) -> Option<(usize, Etiquetado<Vertice>)>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let (vertices, vecinos) = adyacencia(grafo)?;
+    let n = vertices.len();
+    let inicial = colores_dsatur(&vecinos);
+    let num_inicial = inicial.iter().map(|x| x + 1).max().unwrap_or(0);
+    let mut ramificacion = Ramificacion {
+        vecinos: &vecinos,
+        colores: vec![None; n],
+        mejor: inicial,
+        num_mejor: num_inicial,
+        cota_inferior: clique_voraz(&vecinos)
+    };
+    if ramificacion.num_mejor > ramificacion.cota_inferior
+    {
+        ramificacion.colorear(0, n);
+    }
+    Some((ramificacion.num_mejor, etiquetado_colores(&vertices, &ramificacion.mejor)))
+}
+
+///
+/// PRE: Grafo y etiquetado de colores
+/// POST: true si cada vertice tiene exactamente un color y los extremos de cada arista tienen colores distintos.
+/// Un grafo con lazos no tiene coloraciones propias
+/// 
+pub fn es_coloracion_propia<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>, colores: &Etiquetado<Vertice>) -> bool
+where Vertice: VerticeT, Peso: PesoT
+{
+    let mut color: HashMap<&Vertice, isize> = HashMap::new();
+    for etiqueta in colores.get_datos()
+    {
+        if color.insert(etiqueta.get_vertice(), etiqueta.get_valor()).is_some()
+        {
+            return false;
+        }
+    }
+    if grafo.get_vertices().into_iter().any(|v| !color.contains_key(v))
+    {
+        return false;
+    }
+    grafo.get_aristas().iter()
+        .filter_map(|x| x.get_vertices())
+        .all(|(v, w)| color[v] != color[w])
+}
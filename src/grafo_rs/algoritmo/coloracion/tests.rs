@@ -0,0 +1,125 @@
+use crate::grafo_rs::{Arista, Etiqueta, Etiquetado, Grafo, NoPeso};
+use super::*;
+
+fn num_colores(colores: &Etiquetado<i32>) -> isize
+{
+    colores.max().map_or(0, |x| x.get_valor() + 1)
+}
+
+fn ciclo(n: i32) -> Grafo<i32, NoPeso>
+{
+    Grafo::from_aristas((0..n).map(|i| Arista::arista_sin_peso(i, (i + 1) % n)).collect())
+}
+
+///
+/// Grafo de Grötzsch: sin triangulos y con numero cromatico 4
+/// 
+fn grotzsch() -> Grafo<i32, NoPeso>
+{
+    let mut aristas = vec![];
+    for i in 0..5
+    {
+        aristas.push(Arista::arista_sin_peso(i, (i + 1) % 5));
+        aristas.push(Arista::arista_sin_peso(i + 5, (i + 1) % 5));
+        aristas.push(Arista::arista_sin_peso(i + 5, (i + 4) % 5));
+        aristas.push(Arista::arista_sin_peso(i + 5, 10));
+    }
+    Grafo::from_aristas(aristas)
+}
+
+///
+/// Corona: grafo bipartito completo sin un emparejamiento perfecto, con los lados intercalados
+/// 
+fn corona(n: i32) -> Grafo<i32, NoPeso>
+{
+    let mut aristas: Vec<Arista<i32, NoPeso>> = (0..2 * n).map(Arista::vertice).collect();
+    for i in 0..n
+    {
+        for j in (0..n).filter(|j| *j != i)
+        {
+            aristas.push(Arista::arista_sin_peso(2 * i, 2 * j + 1));
+        }
+    }
+    Grafo::from_aristas(aristas)
+}
+
+#[test]
+fn test_coloracion_voraz()
+{
+    let g = grotzsch();
+    for orden in [OrdenColoracion::Natural, OrdenColoracion::GradoDecreciente, OrdenColoracion::MenorUltimo]
+    {
+        let colores = coloracion_voraz(&g, orden).expect("Sin lazos");
+        assert!(es_coloracion_propia(&g, &colores));
+        assert_eq!(colores.get_nombre(), Some("Color"));
+    }
+
+    // En una corona el orden natural es el peor posible
+    let g = corona(4);
+    assert_eq!(num_colores(&coloracion_voraz(&g, OrdenColoracion::Natural).unwrap()), 4);
+    assert_eq!(num_colores(&coloracion_voraz(&g, OrdenColoracion::MenorUltimo).unwrap()), 2);
+
+    let lazo: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(1, 1)].to_vec());
+    assert!(coloracion_voraz(&lazo, OrdenColoracion::Natural).is_none());
+}
+
+#[test]
+fn test_dsatur()
+{
+    // DSatur es exacto en grafos bipartitos
+    let g = corona(5);
+    let colores = dsatur(&g).unwrap();
+    assert!(es_coloracion_propia(&g, &colores));
+    assert_eq!(num_colores(&colores), 2);
+
+    let g = ciclo(7);
+    assert_eq!(num_colores(&dsatur(&g).unwrap()), 3);
+}
+
+#[test]
+fn test_numero_cromatico()
+{
+    assert_eq!(numero_cromatico(&ciclo(6)).unwrap().0, 2);
+    assert_eq!(numero_cromatico(&ciclo(5)).unwrap().0, 3);
+
+    let (k, colores) = numero_cromatico(&grotzsch()).unwrap();
+    assert_eq!(k, 4);
+    assert!(es_coloracion_propia(&grotzsch(), &colores));
+
+    let mut aristas = vec![];
+    for i in 0..5
+    {
+        for j in i + 1..5
+        {
+            aristas.push(Arista::arista_sin_peso(i, j));
+        }
+    }
+    aristas.push(Arista::vertice(9));
+    let completo: Grafo<i32, NoPeso> = Grafo::from_aristas(aristas);
+    let (k, colores) = numero_cromatico(&completo).unwrap();
+    assert_eq!(k, 5);
+    assert_eq!(num_colores(&colores), 5);
+
+    assert_eq!(numero_cromatico(&Grafo::<i32, NoPeso>::new()).unwrap().0, 0);
+}
+
+#[test]
+fn test_es_coloracion_propia()
+{
+    let g = ciclo(4);
+    let colores = Etiquetado::from_vec(None, [Etiqueta::new(0, 0), Etiqueta::new(1, 1),
+                                              Etiqueta::new(2, 0), Etiqueta::new(3, 1)].to_vec());
+    assert!(es_coloracion_propia(&g, &colores));
+
+    let adyacentes = Etiquetado::from_vec(None, [Etiqueta::new(0, 0), Etiqueta::new(1, 0),
+                                                 Etiqueta::new(2, 1), Etiqueta::new(3, 1)].to_vec());
+    assert!(!es_coloracion_propia(&g, &adyacentes));
+
+    let incompleto = Etiquetado::from_vec(None, [Etiqueta::new(0, 0), Etiqueta::new(1, 1),
+                                                 Etiqueta::new(2, 0)].to_vec());
+    assert!(!es_coloracion_propia(&g, &incompleto));
+
+    let repetido = Etiquetado::from_vec(None, [Etiqueta::new(0, 0), Etiqueta::new(1, 1), Etiqueta::new(2, 0),
+                                               Etiqueta::new(3, 1), Etiqueta::new(3, 2)].to_vec());
+    assert!(!es_coloracion_propia(&g, &repetido));
+}
@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+
+use crate::grafo_rs::{AristaT, ConjuntosDisjuntos, Diarista, Digrafo, Etiquetado, Grafo, GrafoT, NoPeso, PesoT, VerticeT};
+
+#[cfg(test)]
+mod tests;
+
+///
+/// Funcion auxiliar. Devuelve los vertices del grafo, el indice de la componente de cada uno y el numero de componentes.
+/// Las componentes se numeran por orden de aparicion de su primer vertice
+/// 
+fn clasificar_vertices<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>) -> (Vec<&Vertice>, Vec<usize>, usize)
+where Vertice: VerticeT, Peso: PesoT
+{
+    let vertices = grafo.get_vertices();
+    let ids: HashMap<&Vertice, usize> = vertices.iter().enumerate()
+                                            .map(|(i, v)| (*v, i))
+                                            .collect();
+    let mut conjuntos = ConjuntosDisjuntos::new(vertices.len());
+    for arista in grafo.get_aristas().iter()
+    {
+        if let Some((v, w)) = arista.get_vertices()
+        {
+            conjuntos.unir(ids[v], ids[w]);
+        }
+    }
+
+    let mut indices: HashMap<usize, usize> = HashMap::new();
+    let componentes = (0..vertices.len())
+                        .map(|id| {
+                            let siguiente = indices.len();
+                            *indices.entry(conjuntos.buscar(id)).or_insert(siguiente)
+                        })
+                        .collect();
+    (vertices, componentes, conjuntos.num_conjuntos())
+}
+
+///
+/// PRE: Grafo
+/// POST: Vector con los vertices de cada componente conexa, por orden de aparicion
+/// 
+pub fn componentes_conexas<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>) -> Vec<Vec<&Vertice>>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let (vertices, componentes, num_componentes) = clasificar_vertices(grafo);
+    let mut res: Vec<Vec<&Vertice>> = vec![vec![]; num_componentes];
+    for (v, componente) in vertices.into_iter().zip(componentes)
+    {
+        res[componente].push(v);
+    }
+    res
+}
+
+///
+/// PRE: Grafo
+/// POST: Vector con el subgrafo inducido por cada componente conexa, en el mismo orden que [`componentes_conexas`]
+/// 
+pub fn subgrafos_conexos<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>) -> Vec<Grafo<Vertice, Peso>>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let (vertices, componentes, num_componentes) = clasificar_vertices(grafo);
+    let componente_vertice: HashMap<&Vertice, usize> = vertices.into_iter()
+                                                        .zip(componentes)
+                                                        .collect();
+    let mut aristas: Vec<Vec<_>> = vec![vec![]; num_componentes];
+    for arista in grafo.get_aristas().iter()
+    {
+        let v = match arista.get_vertices() {
+            Some((v, _)) => v,
+            None => arista.get_vertice_aislado().unwrap()
+        };
+        aristas[componente_vertice[v]].push(arista.clone());
+    }
+    aristas.into_iter().map(Grafo::from_aristas).collect()
+}
+
+///
+/// PRE: Grafo
+/// POST: Etiquetado que asigna a cada vertice el indice de su componente conexa
+/// 
+pub fn etiquetado_componentes<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>) -> Etiquetado<Vertice>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let (vertices, componentes, _) = clasificar_vertices(grafo);
+    let mut etiquetado = Etiquetado::new(Some("Componentes"));
+    for (v, componente) in vertices.into_iter().zip(componentes)
+    {
+        etiquetado.add_vertice(v.clone(), componente as isize);
+    }
+    etiquetado
+}
+
+///
+/// PRE: Grafo
+/// POST: Numero de componentes conexas
+/// 
+pub fn num_componentes<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>) -> usize
+where Vertice: VerticeT, Peso: PesoT
+{
+    clasificar_vertices(grafo).2
+}
+
+///
+/// PRE: Grafo
+/// POST: true si el grafo tiene exactamente una componente conexa. false eoc
+/// NOTA: El grafo vacio no es conexo
+/// 
+pub fn es_conexo<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>) -> bool
+where Vertice: VerticeT, Peso: PesoT
+{
+    num_componentes(grafo) == 1
+}
+
+///
+/// Funcion auxiliar. Devuelve, para cada vertice del digrafo, el indice de su componente fuertemente conexa
+/// y el numero de componentes. Las componentes se numeran en orden topologico de la condensacion
+/// NOTA: Implementacion iterativa del algoritmo de Tarjan
+/// 
+fn clasificar_vertices_fuertes<Vertice, Peso>(digrafo: &Digrafo<Vertice, Peso>) -> (Vec<&Vertice>, Vec<usize>, usize)
+where Vertice: VerticeT, Peso: PesoT
+{
+    let vertices = digrafo.get_vertices();
+    let n = vertices.len();
+    let ids: HashMap<&Vertice, usize> = vertices.iter().enumerate()
+                                            .map(|(i, v)| (*v, i))
+                                            .collect();
+    let sucesores: Vec<Vec<usize>> = vertices.iter()
+                        .map(|v| digrafo.aristas_accesibles(v).into_iter()
+                                    .map(|x| ids[x.get_vertices().unwrap().1])
+                                    .collect())
+                        .collect();
+
+    let mut indices: Vec<Option<usize>> = vec![None; n];
+    let mut minimos: Vec<usize> = vec![0; n];
+    let mut en_pila = vec![false; n];
+    let mut pila: Vec<usize> = vec![];
+    let mut contador: usize = 0;
+    // Componente de cada vertice, en el orden en el que las encuentra Tarjan (topologico inverso)
+    let mut componentes: Vec<usize> = vec![0; n];
+    let mut num_componentes: usize = 0;
+
+    for inicial in 0..n
+    {
+        if indices[inicial].is_some()
+        {
+            continue;
+        }
+        // Pila de llamadas: (vertice, siguiente sucesor por explorar)
+        let mut llamadas: Vec<(usize, usize)> = vec![(inicial, 0)];
+        indices[inicial] = Some(contador);
+        minimos[inicial] = contador;
+        contador += 1;
+        pila.push(inicial);
+        en_pila[inicial] = true;
+
+        while let Some((v, siguiente)) = llamadas.last_mut()
+        {
+            let v = *v;
+            if *siguiente < sucesores[v].len()
+            {
+                let w = sucesores[v][*siguiente];
+                *siguiente += 1;
+                match indices[w] {
+                    None => {
+                        indices[w] = Some(contador);
+                        minimos[w] = contador;
+                        contador += 1;
+                        pila.push(w);
+                        en_pila[w] = true;
+                        llamadas.push((w, 0));
+                    },
+                    Some(indice) => {
+                        if en_pila[w]
+                        {
+                            minimos[v] = minimos[v].min(indice);
+                        }
+                    }
+                }
+                continue;
+            }
+
+            llamadas.pop();
+            if let Some((u, _)) = llamadas.last()
+            {
+                minimos[*u] = minimos[*u].min(minimos[v]);
+            }
+            if Some(minimos[v]) == indices[v]
+            {
+                // v es la raiz de una componente
+                loop {
+                    let w = pila.pop().unwrap();
+                    en_pila[w] = false;
+                    componentes[w] = num_componentes;
+                    if w == v
+                    {
+                        break;
+                    }
+                }
+                num_componentes += 1;
+            }
+        }
+    }
+
+    let componentes = componentes.into_iter()
+                        .map(|x| num_componentes - 1 - x)
+                        .collect();
+    (vertices, componentes, num_componentes)
+}
+
+///
+/// PRE: Digrafo
+/// POST: Vector con los vertices de cada componente fuertemente conexa. Las componentes aparecen en orden
+/// topologico: no hay arcos de una componente a otra anterior
+/// 
+pub fn componentes_fuertemente_conexas<Vertice, Peso>(digrafo: &Digrafo<Vertice, Peso>) -> Vec<Vec<&Vertice>>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let (vertices, componentes, num_componentes) = clasificar_vertices_fuertes(digrafo);
+    let mut res: Vec<Vec<&Vertice>> = vec![vec![]; num_componentes];
+    for (v, componente) in vertices.into_iter().zip(componentes)
+    {
+        res[componente].push(v);
+    }
+    res
+}
+
+///
+/// PRE: Digrafo
+/// POST: Par con las componentes fuertemente conexas (como en [`componentes_fuertemente_conexas`]) y el digrafo
+/// de condensacion. Sus vertices son los indices de las componentes y tiene un arco (i, j) si hay algun arco
+/// de la componente i a la componente j. La condensacion es aciclica y sus arcos cumplen i < j
+/// 
+pub fn condensacion<Vertice, Peso>(digrafo: &Digrafo<Vertice, Peso>) -> (Vec<Vec<&Vertice>>, Digrafo<usize, NoPeso>)
+where Vertice: VerticeT, Peso: PesoT
+{
+    let (vertices, componentes, num_componentes) = clasificar_vertices_fuertes(digrafo);
+    let componente_vertice: HashMap<&Vertice, usize> = vertices.iter()
+                                                        .copied()
+                                                        .zip(componentes.iter().copied())
+                                                        .collect();
+
+    let arcos: Vec<Diarista<usize, NoPeso>> = digrafo.get_aristas().iter()
+                    .filter_map(|x| x.get_vertices())
+                    .map(|(u, v)| (componente_vertice[u], componente_vertice[v]))
+                    .filter(|(i, j)| i != j)
+                    .map(|(i, j)| Diarista::arista_sin_peso(i, j))
+                    .collect();
+    let mut condensado: Digrafo<usize, NoPeso> = Digrafo::from_aristas(arcos);
+    // Las componentes sin arcos se añaden como vertices aislados
+    let aislados = (0..num_componentes).filter(|x| condensado.grado(x).is_none()).collect();
+    condensado.add_vertices(aislados);
+
+    let mut res: Vec<Vec<&Vertice>> = vec![vec![]; num_componentes];
+    for (v, componente) in vertices.into_iter().zip(componentes)
+    {
+        res[componente].push(v);
+    }
+    (res, condensado)
+}
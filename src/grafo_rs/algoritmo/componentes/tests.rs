@@ -0,0 +1,114 @@
+use crate::grafo_rs::{Arista, AristaT, Diarista, Digrafo, Grafo, GrafoT, NoPeso};
+use super::*;
+
+#[test]
+fn test_componentes_conexas()
+{
+    let g: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(1, 2),
+                                                        Arista::arista_sin_peso(5, 6),
+                                                        Arista::arista_sin_peso(2, 3),
+                                                        Arista::vertice(10),
+                                                        Arista::arista_sin_peso(6, 7),
+                                                        Arista::arista_sin_peso(3, 1)].to_vec());
+    let componentes = componentes_conexas(&g);
+    assert_eq!(componentes, vec![vec![&1, &2, &3], vec![&5, &6, &7], vec![&10]]);
+    assert_eq!(num_componentes(&g), 3);
+    assert!(!es_conexo(&g));
+
+    let etiquetado = etiquetado_componentes(&g);
+    assert_eq!(etiquetado.get_nombre(), Some("Componentes"));
+    assert_eq!(etiquetado.buscar_vertice(&3).unwrap().get_valor(), 0);
+    assert_eq!(etiquetado.buscar_vertice(&7).unwrap().get_valor(), 1);
+    assert_eq!(etiquetado.buscar_vertice(&10).unwrap().get_valor(), 2);
+}
+
+#[test]
+fn test_subgrafos_conexos()
+{
+    let g: Grafo<char, usize> = Grafo::from_aristas([Arista::arista('a', 'b', Some(1)),
+                                                        Arista::arista('x', 'y', Some(2)),
+                                                        Arista::arista('b', 'c', Some(3)),
+                                                        Arista::arista('c', 'c', Some(4)),
+                                                        Arista::vertice('q')].to_vec());
+    let subgrafos = subgrafos_conexos(&g);
+    assert_eq!(subgrafos.len(), 3);
+    assert_eq!(subgrafos[0].get_aristas().len(), 3);
+    assert_eq!(subgrafos[0].grado(&'c'), Some(3));
+    assert_eq!(subgrafos[1].get_vertices(), vec![&'x', &'y']);
+    assert!(subgrafos[2].get_aristas() == &vec![Arista::vertice('q')]);
+}
+
+#[test]
+fn test_es_conexo()
+{
+    let g: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(1, 2),
+                                                        Arista::arista_sin_peso(2, 3)].to_vec());
+    assert!(es_conexo(&g));
+    assert!(!es_conexo(&Grafo::<i32, NoPeso>::new()), "El grafo vacio no es conexo");
+    assert!(es_conexo(&Grafo::<i32, NoPeso>::from_aristas(vec![Arista::vertice(1)])));
+}
+
+#[test]
+fn test_componentes_fuertemente_conexas()
+{
+    let dg: Digrafo<char> = Digrafo::from_aristas([Diarista::arista_sin_peso('a', 'b'),
+                                                    Diarista::arista_sin_peso('b', 'c'),
+                                                    Diarista::arista_sin_peso('b', 'e'),
+                                                    Diarista::arista_sin_peso('b', 'f'),
+                                                    Diarista::arista_sin_peso('c', 'd'),
+                                                    Diarista::arista_sin_peso('c', 'g'),
+                                                    Diarista::arista_sin_peso('d', 'c'),
+                                                    Diarista::arista_sin_peso('d', 'h'),
+                                                    Diarista::arista_sin_peso('e', 'a'),
+                                                    Diarista::arista_sin_peso('e', 'f'),
+                                                    Diarista::arista_sin_peso('f', 'g'),
+                                                    Diarista::arista_sin_peso('g', 'f'),
+                                                    Diarista::arista_sin_peso('g', 'h'),
+                                                    Diarista::arista_sin_peso('h', 'h'),
+                                                    Diarista::vertice('z')].to_vec());
+    let mut componentes: Vec<Vec<char>> = componentes_fuertemente_conexas(&dg).into_iter()
+                                .map(|c| { let mut c: Vec<char> = c.into_iter().copied().collect(); c.sort(); c })
+                                .collect();
+    assert_eq!(componentes.len(), 5);
+    componentes.sort();
+    assert_eq!(componentes, vec![vec!['a', 'b', 'e'], vec!['c', 'd'], vec!['f', 'g'], vec!['h'], vec!['z']]);
+}
+
+#[test]
+fn test_condensacion()
+{
+    let dg: Digrafo<i32> = Digrafo::from_aristas([Diarista::arista_sin_peso(1, 2),
+                                                    Diarista::arista_sin_peso(2, 3),
+                                                    Diarista::arista_sin_peso(3, 1),
+                                                    Diarista::arista_sin_peso(3, 4),
+                                                    Diarista::arista_sin_peso(2, 4),
+                                                    Diarista::arista_sin_peso(4, 5),
+                                                    Diarista::arista_sin_peso(5, 4),
+                                                    Diarista::arista_sin_peso(6, 5)].to_vec());
+    let (componentes, condensado) = condensacion(&dg);
+    assert_eq!(componentes.len(), 3);
+    assert_eq!(condensado.size(), 3);
+    assert_eq!(condensado.get_aristas().len(), 2, "Los arcos entre componentes no se repiten");
+
+    let componente = |v: i32| componentes.iter().position(|c| c.contains(&&v)).unwrap();
+    assert_eq!(componente(1), componente(3));
+    assert_eq!(componente(4), componente(5));
+    assert!(condensado.get_aristas().contains(&Diarista::arista_sin_peso(componente(1), componente(4))));
+    assert!(condensado.get_aristas().contains(&Diarista::arista_sin_peso(componente(6), componente(5))));
+    for arco in condensado.get_aristas()
+    {
+        let (i, j) = arco.get_vertices().unwrap();
+        assert!(i < j, "Las componentes estan en orden topologico");
+    }
+}
+
+#[test]
+fn test_componentes_fuertes_camino_largo()
+{
+    // Un camino largo no debe desbordar la pila
+    let dg: Digrafo<usize> = Digrafo::from_aristas((0..20000)
+                                                    .map(|i| Diarista::arista_sin_peso(i, i + 1))
+                                                    .collect());
+    assert_eq!(componentes_fuertemente_conexas(&dg).len(), 20001);
+    assert_eq!(componentes_fuertemente_conexas(&dg)[0], vec![&0]);
+}
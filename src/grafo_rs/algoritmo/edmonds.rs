@@ -0,0 +1,899 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::grafo_rs::{Arista, AristaT, Emparejamiento, Grafo, GrafoT, PesoT, VerticeT};
+use crate::grafo_rs::algoritmo::Asignacion;
+
+#[cfg(test)]
+mod tests;
+
+///
+/// Indice ausente en las estructuras del algoritmo ponderado
+/// 
+const NINGUNO: usize = usize::MAX;
+
+///
+/// Funcion auxiliar. Elemento de una lista circular con indices negativos contados desde el final
+/// 
+fn circular(lista: &[usize], j: isize) -> usize
+{
+    lista[j.rem_euclid(lista.len() as isize) as usize]
+}
+
+///
+/// Estructura auxiliar del algoritmo de Edmonds de cardinal maximo. Las flores se contraen asignando a sus
+/// vertices una base comun
+/// 
+struct Flores
+{
+    vecinos: Vec<Vec<usize>>,
+    pareja: Vec<Option<usize>>,
+    padre: Vec<Option<usize>>,
+    base: Vec<usize>,
+    usados: Vec<bool>,
+    en_flor: Vec<bool>
+}
+
+impl Flores
+{
+    fn new(vecinos: Vec<Vec<usize>>) -> Self
+    {
+        let n = vecinos.len();
+        Self {
+            vecinos,
+            pareja: vec![None; n],
+            padre: vec![None; n],
+            base: (0..n).collect(),
+            usados: vec![false; n],
+            en_flor: vec![false; n]
+        }
+    }
+
+    ///
+    /// POST: Base de la flor formada al unir los caminos alternados de a y b hasta la raiz
+    /// 
+    fn antecesor_comun(&self, mut a: usize, mut b: usize) -> usize
+    {
+        let mut marcados = vec![false; self.base.len()];
+        loop
+        {
+            a = self.base[a];
+            marcados[a] = true;
+            match self.pareja[a] {
+                Some(x) => a = self.padre[x].unwrap(),
+                None => break
+            }
+        }
+        loop
+        {
+            b = self.base[b];
+            if marcados[b]
+            {
+                return b;
+            }
+            b = self.padre[self.pareja[b].unwrap()].unwrap();
+        }
+    }
+
+    ///
+    /// POST: Marca las bases del camino de v hasta la base de la flor y reorienta sus padres hacia hijo
+    /// 
+    fn marcar_camino(&mut self, mut v: usize, base: usize, mut hijo: usize)
+    {
+        while self.base[v] != base
+        {
+            let w = self.pareja[v].unwrap();
+            self.en_flor[self.base[v]] = true;
+            self.en_flor[self.base[w]] = true;
+            self.padre[v] = Some(hijo);
+            hijo = w;
+            v = self.padre[w].unwrap();
+        }
+    }
+
+    ///
+    /// POST: Extremo libre de un camino de aumento desde la raiz, con los padres del camino. None si no existe
+    /// 
+    fn buscar_camino(&mut self, raiz: usize) -> Option<usize>
+    {
+        let n = self.base.len();
+        self.usados.iter_mut().for_each(|x| *x = false);
+        self.padre.iter_mut().for_each(|x| *x = None);
+        self.base.iter_mut().enumerate().for_each(|(i, x)| *x = i);
+        self.usados[raiz] = true;
+        let mut cola = VecDeque::from([raiz]);
+        while let Some(v) = cola.pop_front()
+        {
+            for indice in 0..self.vecinos[v].len()
+            {
+                let w = self.vecinos[v][indice];
+                if self.base[v] == self.base[w] || self.pareja[v] == Some(w)
+                {
+                    continue;
+                }
+                if w == raiz || self.pareja[w].is_some_and(|x| self.padre[x].is_some())
+                {
+                    // Ciclo impar: se contrae la flor
+                    let base = self.antecesor_comun(v, w);
+                    self.en_flor.iter_mut().for_each(|x| *x = false);
+                    self.marcar_camino(v, base, w);
+                    self.marcar_camino(w, base, v);
+                    for i in 0..n
+                    {
+                        if self.en_flor[self.base[i]]
+                        {
+                            self.base[i] = base;
+                            if !self.usados[i]
+                            {
+                                self.usados[i] = true;
+                                cola.push_back(i);
+                            }
+                        }
+                    }
+                }
+                else if self.padre[w].is_none()
+                {
+                    self.padre[w] = Some(v);
+                    match self.pareja[w] {
+                        None => return Some(w),
+                        Some(x) => {
+                            self.usados[x] = true;
+                            cola.push_back(x);
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    fn resolver(&mut self)
+    {
+        for raiz in 0..self.base.len()
+        {
+            if self.pareja[raiz].is_some()
+            {
+                continue;
+            }
+            let mut extremo = self.buscar_camino(raiz);
+            while let Some(v) = extremo
+            {
+                let w = self.padre[v].unwrap();
+                extremo = self.pareja[w];
+                self.pareja[v] = Some(w);
+                self.pareja[w] = Some(v);
+            }
+        }
+    }
+}
+
+///
+/// Funcion auxiliar. Camino de aumento respecto a las parejas dadas, como sucesion de vertices entre dos vertices
+/// libres. None si no existe
+/// 
+pub(crate) fn camino_aumento(vecinos: Vec<Vec<usize>>, pareja: Vec<Option<usize>>) -> Option<Vec<usize>>
+{
+    let mut flores = Flores::new(vecinos);
+    flores.pareja = pareja;
+    for raiz in 0..flores.base.len()
+    {
+        if flores.pareja[raiz].is_some()
+        {
+            continue;
+        }
+        if let Some(mut v) = flores.buscar_camino(raiz)
+        {
+            let mut camino = vec![v];
+            loop
+            {
+                let w = flores.padre[v].unwrap();
+                camino.push(w);
+                match flores.pareja[w] {
+                    Some(x) => {
+                        camino.push(x);
+                        v = x;
+                    },
+                    None => return Some(camino)
+                }
+            }
+        }
+    }
+    None
+}
+
+///
+/// PRE: Grafo
+/// POST: Emparejamiento de cardinal maximo formado por aristas del grafo. Los lazos se ignoran
+/// NOTA: Algoritmo de las flores de Edmonds. O(n³)
+/// 
+pub fn emparejamiento_maximo<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>) -> Emparejamiento<'_, Arista<Vertice, Peso>, Vertice, Peso>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let vertices = grafo.get_vertices();
+    let ids: HashMap<&Vertice, usize> = vertices.iter().enumerate()
+                                            .map(|(i, v)| (*v, i))
+                                            .collect();
+    let mut vecinos: Vec<Vec<usize>> = vec![vec![]; vertices.len()];
+    let mut aristas: HashMap<(usize, usize), &Arista<Vertice, Peso>> = HashMap::new();
+    for arista in grafo.get_aristas()
+    {
+        if let Some((v, w)) = arista.get_vertices()
+        {
+            let (i, j) = (ids[v], ids[w]);
+            if i != j && !aristas.contains_key(&(i.min(j), i.max(j)))
+            {
+                aristas.insert((i.min(j), i.max(j)), arista);
+                vecinos[i].push(j);
+                vecinos[j].push(i);
+            }
+        }
+    }
+
+    let mut flores = Flores::new(vecinos);
+    flores.resolver();
+    let emparejadas = flores.pareja.iter().enumerate()
+                        .filter_map(|(i, x)| x.filter(|j| i < *j).map(|j| aristas[&(i, j)]))
+                        .collect();
+    Emparejamiento::from_disjuntas(emparejadas)
+}
+
+///
+/// Estructura auxiliar del algoritmo de Edmonds de peso maximo, con variables duales por vertice y por flor.
+/// Los vertices son 0..n y las flores n..2n. Cada arista k tiene los extremos 2k y 2k + 1
+/// NOTA: Los duales se guardan duplicados para trabajar solo con enteros. Etiquetas: 0 libre, 1 exterior (S),
+/// 2 interior (T) y 5 como marca temporal de exterior
+/// 
+struct FloresPonderadas
+{
+    n: usize,
+    aristas: Vec<(usize, usize, isize)>,
+    extremos: Vec<usize>,
+    vecinos: Vec<Vec<usize>>,
+    pareja: Vec<usize>,
+    etiqueta: Vec<u8>,
+    extremo_etiqueta: Vec<usize>,
+    en_flor: Vec<usize>,
+    padre_flor: Vec<usize>,
+    hijos_flor: Vec<Vec<usize>>,
+    base_flor: Vec<usize>,
+    extremos_flor: Vec<Vec<usize>>,
+    mejor_arista: Vec<usize>,
+    mejores_aristas_flor: Vec<Option<Vec<usize>>>,
+    libres: Vec<usize>,
+    dual: Vec<isize>,
+    permitida: Vec<bool>,
+    cola: Vec<usize>
+}
+
+impl FloresPonderadas
+{
+    fn new(n: usize, aristas: Vec<(usize, usize, isize)>) -> Self
+    {
+        let peso_maximo = aristas.iter().map(|x| x.2).max().unwrap_or(0).max(0);
+        let extremos = aristas.iter().flat_map(|(i, j, _)| [*i, *j]).collect();
+        let mut vecinos = vec![vec![]; n];
+        for (k, (i, j, _)) in aristas.iter().enumerate()
+        {
+            vecinos[*i].push(2 * k + 1);
+            vecinos[*j].push(2 * k);
+        }
+        Self {
+            n,
+            extremos,
+            vecinos,
+            pareja: vec![NINGUNO; n],
+            etiqueta: vec![0; 2 * n],
+            extremo_etiqueta: vec![NINGUNO; 2 * n],
+            en_flor: (0..n).collect(),
+            padre_flor: vec![NINGUNO; 2 * n],
+            hijos_flor: vec![vec![]; 2 * n],
+            base_flor: (0..n).chain(std::iter::repeat_n(NINGUNO, n)).collect(),
+            extremos_flor: vec![vec![]; 2 * n],
+            mejor_arista: vec![NINGUNO; 2 * n],
+            mejores_aristas_flor: vec![None; 2 * n],
+            libres: (n..2 * n).collect(),
+            dual: std::iter::repeat_n(peso_maximo, n).chain(std::iter::repeat_n(0, n)).collect(),
+            permitida: vec![false; aristas.len()],
+            cola: vec![],
+            aristas
+        }
+    }
+
+    ///
+    /// POST: Holgura de la arista k respecto a los duales de sus extremos. Nunca negativa
+    /// 
+    fn holgura(&self, k: usize) -> isize
+    {
+        let (i, j, peso) = self.aristas[k];
+        self.dual[i] + self.dual[j] - 2 * peso
+    }
+
+    ///
+    /// POST: Vertices contenidos en la flor b, o el propio b si es un vertice
+    /// 
+    fn hojas(&self, b: usize) -> Vec<usize>
+    {
+        let mut hojas = vec![];
+        let mut pila = vec![b];
+        while let Some(t) = pila.pop()
+        {
+            if t < self.n
+            {
+                hojas.push(t);
+            }
+            else
+            {
+                pila.extend(self.hijos_flor[t].iter().rev());
+            }
+        }
+        hojas
+    }
+
+    ///
+    /// POST: Etiqueta el vertice w y su flor con t, alcanzados por el extremo p. Una flor interior etiqueta
+    /// tambien como exterior a la pareja de su base
+    /// 
+    fn etiquetar(&mut self, mut w: usize, mut t: u8, mut p: usize)
+    {
+        loop
+        {
+            let b = self.en_flor[w];
+            self.etiqueta[w] = t;
+            self.etiqueta[b] = t;
+            self.extremo_etiqueta[w] = p;
+            self.extremo_etiqueta[b] = p;
+            self.mejor_arista[w] = NINGUNO;
+            self.mejor_arista[b] = NINGUNO;
+            if t == 1
+            {
+                let hojas = self.hojas(b);
+                self.cola.extend(hojas);
+                return;
+            }
+            let pareja = self.pareja[self.base_flor[b]];
+            w = self.extremos[pareja];
+            t = 1;
+            p = pareja ^ 1;
+        }
+    }
+
+    ///
+    /// POST: Base de la nueva flor formada por la arista entre v y w, ambos exteriores. NINGUNO si sus caminos
+    /// llegan a raices distintas y hay un camino de aumento
+    /// 
+    fn buscar_flor(&mut self, mut v: usize, mut w: usize) -> usize
+    {
+        let mut camino = vec![];
+        let mut base = NINGUNO;
+        while v != NINGUNO || w != NINGUNO
+        {
+            let b = self.en_flor[v];
+            if self.etiqueta[b] & 4 != 0
+            {
+                base = self.base_flor[b];
+                break;
+            }
+            camino.push(b);
+            self.etiqueta[b] = 5;
+            v = match self.extremo_etiqueta[b] {
+                NINGUNO => NINGUNO,
+                p => {
+                    let t = self.en_flor[self.extremos[p]];
+                    self.extremos[self.extremo_etiqueta[t]]
+                }
+            };
+            if w != NINGUNO
+            {
+                std::mem::swap(&mut v, &mut w);
+            }
+        }
+        for b in camino
+        {
+            self.etiqueta[b] = 1;
+        }
+        base
+    }
+
+    ///
+    /// POST: Crea una flor con base dada a partir de la arista k entre dos vertices exteriores
+    /// 
+    fn crear_flor(&mut self, base: usize, k: usize)
+    {
+        let (v, w, _) = self.aristas[k];
+        let bb = self.en_flor[base];
+        let (mut bv, mut bw) = (self.en_flor[v], self.en_flor[w]);
+        let b = self.libres.pop().unwrap();
+        self.base_flor[b] = base;
+        self.padre_flor[b] = NINGUNO;
+        self.padre_flor[bb] = b;
+
+        // Subflores en orden ciclico desde la base y extremos de las aristas que las unen
+        let mut camino = vec![];
+        let mut extremos = vec![];
+        while bv != bb
+        {
+            self.padre_flor[bv] = b;
+            camino.push(bv);
+            extremos.push(self.extremo_etiqueta[bv]);
+            bv = self.en_flor[self.extremos[self.extremo_etiqueta[bv]]];
+        }
+        camino.push(bb);
+        camino.reverse();
+        extremos.reverse();
+        extremos.push(2 * k);
+        while bw != bb
+        {
+            self.padre_flor[bw] = b;
+            camino.push(bw);
+            extremos.push(self.extremo_etiqueta[bw] ^ 1);
+            bw = self.en_flor[self.extremos[self.extremo_etiqueta[bw]]];
+        }
+        self.hijos_flor[b] = camino.clone();
+        self.extremos_flor[b] = extremos;
+        self.etiqueta[b] = 1;
+        self.extremo_etiqueta[b] = self.extremo_etiqueta[bb];
+        self.dual[b] = 0;
+        for v in self.hojas(b)
+        {
+            if self.etiqueta[self.en_flor[v]] == 2
+            {
+                // Los vertices interiores pasan a ser exteriores
+                self.cola.push(v);
+            }
+            self.en_flor[v] = b;
+        }
+
+        // Mejor arista de la nueva flor hacia cada flor exterior vecina
+        let mut mejor_hacia = vec![NINGUNO; 2 * self.n];
+        for bv in camino
+        {
+            let listas: Vec<Vec<usize>> = match self.mejores_aristas_flor[bv].take() {
+                Some(lista) => vec![lista],
+                None => self.hojas(bv).into_iter().map(|v| self.vecinos[v].iter().map(|p| p / 2).collect()).collect()
+            };
+            for k in listas.into_iter().flatten()
+            {
+                let (i, j, _) = self.aristas[k];
+                let bj = match self.en_flor[j] == b {
+                    true => self.en_flor[i],
+                    false => self.en_flor[j]
+                };
+                if bj != b && self.etiqueta[bj] == 1
+                    && (mejor_hacia[bj] == NINGUNO || self.holgura(k) < self.holgura(mejor_hacia[bj]))
+                {
+                    mejor_hacia[bj] = k;
+                }
+            }
+            self.mejor_arista[bv] = NINGUNO;
+        }
+        let lista: Vec<usize> = mejor_hacia.into_iter().filter(|k| *k != NINGUNO).collect();
+        self.mejor_arista[b] = lista.iter().copied().min_by_key(|k| self.holgura(*k)).unwrap_or(NINGUNO);
+        self.mejores_aristas_flor[b] = Some(lista);
+    }
+
+    ///
+    /// POST: Deshace la flor b. Fuera del final de una fase, si era interior se reetiquetan sus subflores
+    /// 
+    fn expandir_flor(&mut self, b: usize, final_fase: bool)
+    {
+        let hijos = self.hijos_flor[b].clone();
+        for s in hijos.iter().copied()
+        {
+            self.padre_flor[s] = NINGUNO;
+            if s < self.n
+            {
+                self.en_flor[s] = s;
+            }
+            else if final_fase && self.dual[s] == 0
+            {
+                self.expandir_flor(s, final_fase);
+            }
+            else
+            {
+                for v in self.hojas(s)
+                {
+                    self.en_flor[v] = s;
+                }
+            }
+        }
+
+        if !final_fase && self.etiqueta[b] == 2
+        {
+            // Se recorre la flor desde la subflor de entrada hasta la base por el lado de longitud par
+            let extremos = self.extremos_flor[b].clone();
+            let entrada = self.en_flor[self.extremos[self.extremo_etiqueta[b] ^ 1]];
+            let mut j = hijos.iter().position(|x| *x == entrada).unwrap() as isize;
+            let (paso, truco) = match j & 1 {
+                1 => {
+                    j -= hijos.len() as isize;
+                    (1, 0)
+                },
+                _ => (-1, 1)
+            };
+            let mut p = self.extremo_etiqueta[b];
+            while j != 0
+            {
+                self.etiqueta[self.extremos[p ^ 1]] = 0;
+                self.etiqueta[self.extremos[circular(&extremos, j - truco) ^ truco as usize ^ 1]] = 0;
+                self.etiquetar(self.extremos[p ^ 1], 2, p);
+                self.permitida[circular(&extremos, j - truco) / 2] = true;
+                j += paso;
+                p = circular(&extremos, j - truco) ^ truco as usize;
+                self.permitida[p / 2] = true;
+                j += paso;
+            }
+            let bv = circular(&hijos, j);
+            let v = self.extremos[p ^ 1];
+            self.etiqueta[v] = 2;
+            self.etiqueta[bv] = 2;
+            self.extremo_etiqueta[v] = p;
+            self.extremo_etiqueta[bv] = p;
+            self.mejor_arista[bv] = NINGUNO;
+
+            // El resto de subflores solo conservan la etiqueta si fueron alcanzadas desde fuera
+            j += paso;
+            while circular(&hijos, j) != entrada
+            {
+                let bv = circular(&hijos, j);
+                j += paso;
+                if self.etiqueta[bv] == 1
+                {
+                    continue;
+                }
+                if let Some(v) = self.hojas(bv).into_iter().find(|v| self.etiqueta[*v] != 0)
+                {
+                    self.etiqueta[v] = 0;
+                    self.etiqueta[self.extremos[self.pareja[self.base_flor[bv]]]] = 0;
+                    self.etiquetar(v, 2, self.extremo_etiqueta[v]);
+                }
+            }
+        }
+
+        self.etiqueta[b] = 0;
+        self.extremo_etiqueta[b] = NINGUNO;
+        self.hijos_flor[b] = vec![];
+        self.extremos_flor[b] = vec![];
+        self.base_flor[b] = NINGUNO;
+        self.mejores_aristas_flor[b] = None;
+        self.mejor_arista[b] = NINGUNO;
+        self.libres.push(b);
+    }
+
+    ///
+    /// POST: Intercambia las aristas emparejadas del camino par dentro de la flor b desde v hasta su base,
+    /// que pasa a ser v
+    /// 
+    fn aumentar_flor(&mut self, b: usize, v: usize)
+    {
+        let mut t = v;
+        while self.padre_flor[t] != b
+        {
+            t = self.padre_flor[t];
+        }
+        if t >= self.n
+        {
+            self.aumentar_flor(t, v);
+        }
+        let hijos = self.hijos_flor[b].clone();
+        let extremos = self.extremos_flor[b].clone();
+        let i = hijos.iter().position(|x| *x == t).unwrap();
+        let mut j = i as isize;
+        let (paso, truco) = match i & 1 {
+            1 => {
+                j -= hijos.len() as isize;
+                (1, 0)
+            },
+            _ => (-1, 1)
+        };
+        while j != 0
+        {
+            j += paso;
+            let p = circular(&extremos, j - truco) ^ truco as usize;
+            let t = circular(&hijos, j);
+            if t >= self.n
+            {
+                self.aumentar_flor(t, self.extremos[p]);
+            }
+            j += paso;
+            let t = circular(&hijos, j);
+            if t >= self.n
+            {
+                self.aumentar_flor(t, self.extremos[p ^ 1]);
+            }
+            self.pareja[self.extremos[p]] = p ^ 1;
+            self.pareja[self.extremos[p ^ 1]] = p;
+        }
+        self.hijos_flor[b].rotate_left(i);
+        self.extremos_flor[b].rotate_left(i);
+        self.base_flor[b] = self.base_flor[self.hijos_flor[b][0]];
+    }
+
+    ///
+    /// POST: Aplica el camino de aumento que pasa por la arista k entre dos vertices exteriores
+    /// 
+    fn aumentar(&mut self, k: usize)
+    {
+        let (v, w, _) = self.aristas[k];
+        for (mut s, mut p) in [(v, 2 * k + 1), (w, 2 * k)]
+        {
+            loop
+            {
+                let bs = self.en_flor[s];
+                if bs >= self.n
+                {
+                    self.aumentar_flor(bs, s);
+                }
+                self.pareja[s] = p;
+                if self.extremo_etiqueta[bs] == NINGUNO
+                {
+                    break;
+                }
+                let bt = self.en_flor[self.extremos[self.extremo_etiqueta[bs]]];
+                s = self.extremos[self.extremo_etiqueta[bt]];
+                let j = self.extremos[self.extremo_etiqueta[bt] ^ 1];
+                if bt >= self.n
+                {
+                    self.aumentar_flor(bt, j);
+                }
+                self.pareja[j] = self.extremo_etiqueta[bt];
+                p = self.extremo_etiqueta[bt] ^ 1;
+            }
+        }
+    }
+
+    ///
+    /// POST: Recorre la cola de vertices exteriores. true si se ha aumentado el emparejamiento
+    /// 
+    fn explorar(&mut self) -> bool
+    {
+        while let Some(v) = self.cola.pop()
+        {
+            for p in self.vecinos[v].clone()
+            {
+                let k = p / 2;
+                let w = self.extremos[p];
+                if self.en_flor[v] == self.en_flor[w]
+                {
+                    continue;
+                }
+                let mut holgura = 0;
+                if !self.permitida[k]
+                {
+                    holgura = self.holgura(k);
+                    self.permitida[k] = holgura <= 0;
+                }
+                if self.permitida[k]
+                {
+                    match self.etiqueta[self.en_flor[w]] {
+                        0 => self.etiquetar(w, 2, p ^ 1),
+                        1 => {
+                            let base = self.buscar_flor(v, w);
+                            if base == NINGUNO
+                            {
+                                self.aumentar(k);
+                                return true;
+                            }
+                            self.crear_flor(base, k);
+                        },
+                        _ => {
+                            if self.etiqueta[w] == 0
+                            {
+                                self.etiqueta[w] = 2;
+                                self.extremo_etiqueta[w] = p ^ 1;
+                            }
+                        }
+                    }
+                }
+                else if self.etiqueta[self.en_flor[w]] == 1
+                {
+                    let b = self.en_flor[v];
+                    if self.mejor_arista[b] == NINGUNO || holgura < self.holgura(self.mejor_arista[b])
+                    {
+                        self.mejor_arista[b] = k;
+                    }
+                }
+                else if self.etiqueta[w] == 0
+                    && (self.mejor_arista[w] == NINGUNO || holgura < self.holgura(self.mejor_arista[w]))
+                {
+                    self.mejor_arista[w] = k;
+                }
+            }
+        }
+        false
+    }
+
+    ///
+    /// POST: Ajusta los duales por el mayor cambio posible. false si el emparejamiento ya es optimo
+    /// 
+    fn ajustar_duales(&mut self) -> bool
+    {
+        let n = self.n;
+        // Tipo 1: un dual de vertice llega a 0
+        let mut tipo = 1;
+        let mut delta = self.dual[..n].iter().copied().min().unwrap();
+        let mut arista = NINGUNO;
+        let mut flor = NINGUNO;
+        // Tipo 2: arista ajustada entre un vertice exterior y uno libre
+        for v in 0..n
+        {
+            if self.etiqueta[self.en_flor[v]] == 0 && self.mejor_arista[v] != NINGUNO
+                && self.holgura(self.mejor_arista[v]) < delta
+            {
+                delta = self.holgura(self.mejor_arista[v]);
+                (tipo, arista) = (2, self.mejor_arista[v]);
+            }
+        }
+        // Tipo 3: arista ajustada entre dos flores exteriores
+        for b in 0..2 * n
+        {
+            if self.padre_flor[b] == NINGUNO && self.etiqueta[b] == 1 && self.mejor_arista[b] != NINGUNO
+                && self.holgura(self.mejor_arista[b]) / 2 < delta
+            {
+                delta = self.holgura(self.mejor_arista[b]) / 2;
+                (tipo, arista) = (3, self.mejor_arista[b]);
+            }
+        }
+        // Tipo 4: el dual de una flor interior llega a 0
+        for b in n..2 * n
+        {
+            if self.base_flor[b] != NINGUNO && self.padre_flor[b] == NINGUNO && self.etiqueta[b] == 2
+                && self.dual[b] < delta
+            {
+                delta = self.dual[b];
+                (tipo, flor) = (4, b);
+            }
+        }
+
+        for v in 0..n
+        {
+            match self.etiqueta[self.en_flor[v]] {
+                1 => self.dual[v] -= delta,
+                2 => self.dual[v] += delta,
+                _ => {}
+            }
+        }
+        for b in n..2 * n
+        {
+            if self.base_flor[b] != NINGUNO && self.padre_flor[b] == NINGUNO
+            {
+                match self.etiqueta[b] {
+                    1 => self.dual[b] += delta,
+                    2 => self.dual[b] -= delta,
+                    _ => {}
+                }
+            }
+        }
+
+        match tipo {
+            1 => return false,
+            2 => {
+                self.permitida[arista] = true;
+                let (i, j, _) = self.aristas[arista];
+                self.cola.push(if self.etiqueta[self.en_flor[i]] == 0 { j } else { i });
+            },
+            3 => {
+                self.permitida[arista] = true;
+                self.cola.push(self.aristas[arista].0);
+            },
+            _ => self.expandir_flor(flor, false)
+        }
+        true
+    }
+
+    fn resolver(&mut self)
+    {
+        let n = self.n;
+        for _ in 0..n
+        {
+            self.etiqueta.iter_mut().for_each(|x| *x = 0);
+            self.mejor_arista.iter_mut().for_each(|x| *x = NINGUNO);
+            self.mejores_aristas_flor[n..].iter_mut().for_each(|x| *x = None);
+            self.permitida.iter_mut().for_each(|x| *x = false);
+            self.cola.clear();
+            for v in 0..n
+            {
+                if self.pareja[v] == NINGUNO && self.etiqueta[self.en_flor[v]] == 0
+                {
+                    self.etiquetar(v, 1, NINGUNO);
+                }
+            }
+
+            let mut aumentado = false;
+            loop
+            {
+                if self.explorar()
+                {
+                    aumentado = true;
+                    break;
+                }
+                if !self.ajustar_duales()
+                {
+                    break;
+                }
+            }
+            if !aumentado
+            {
+                break;
+            }
+
+            // Las flores exteriores con dual nulo se deshacen al final de cada fase
+            for b in n..2 * n
+            {
+                if self.padre_flor[b] == NINGUNO && self.base_flor[b] != NINGUNO && self.etiqueta[b] == 1
+                    && self.dual[b] == 0
+                {
+                    self.expandir_flor(b, true);
+                }
+            }
+        }
+    }
+}
+
+///
+/// Funcion auxiliar. Pareja de cada vertice 0..n en un emparejamiento de peso maximo con las aristas (i, j, peso)
+/// dadas. None si el vertice queda libre
+/// 
+pub(crate) fn parejas_peso_maximo(n: usize, aristas: Vec<(usize, usize, isize)>) -> Vec<Option<usize>>
+{
+    let mut flores = FloresPonderadas::new(n, aristas);
+    flores.resolver();
+    flores.pareja.iter()
+        .map(|p| match *p {
+            NINGUNO => None,
+            p => Some(flores.extremos[p])
+        })
+        .collect()
+}
+
+///
+/// PRE: Grafo con pesos
+/// POST: Emparejamiento de peso total maximo, sin importar su cardinal, junto a dicho peso. Las aristas de peso
+/// no positivo y los lazos no se usan. None si alguna arista no tiene peso
+/// NOTA: Algoritmo de las flores de Edmonds con variables duales. O(n³). Los pesos se comparan con
+/// `PesoT::to_isize`. Entre aristas paralelas se elige la de mayor peso
+/// 
+pub fn emparejamiento_peso_maximo<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>) -> Option<Asignacion<'_, Vertice, Peso>>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let vertices = grafo.get_vertices();
+    let ids: HashMap<&Vertice, usize> = vertices.iter().enumerate()
+                                            .map(|(i, v)| (*v, i))
+                                            .collect();
+    // Una arista por par de vertices, la de mayor peso
+    let mut indices: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut aristas: Vec<(usize, usize, isize)> = vec![];
+    let mut originales: Vec<&Arista<Vertice, Peso>> = vec![];
+    for arista in grafo.get_aristas()
+    {
+        if let Some((v, w)) = arista.get_vertices()
+        {
+            let peso = arista.get_peso()?.to_isize();
+            let (i, j) = (ids[v], ids[w]);
+            if i == j || peso <= 0
+            {
+                continue;
+            }
+            match indices.get(&(i.min(j), i.max(j))) {
+                Some(k) if aristas[*k].2 < peso => {
+                    aristas[*k].2 = peso;
+                    originales[*k] = arista;
+                },
+                Some(_) => {},
+                None => {
+                    indices.insert((i.min(j), i.max(j)), aristas.len());
+                    aristas.push((i, j, peso));
+                    originales.push(arista);
+                }
+            }
+        }
+    }
+
+    let mut flores = FloresPonderadas::new(vertices.len(), aristas);
+    flores.resolver();
+    // Cada arista emparejada se toma desde su extremo de menor indice
+    let emparejadas: Vec<&Arista<Vertice, Peso>> = (0..vertices.len())
+                        .filter(|v| flores.pareja[*v] != NINGUNO && flores.extremos[flores.pareja[*v]] > *v)
+                        .map(|v| originales[flores.pareja[v] / 2])
+                        .collect();
+    let total = emparejadas.iter().fold(Peso::elemento_neutro(), |acc, x| acc.suma(x.get_peso().unwrap()));
+    Some((Emparejamiento::from_disjuntas(emparejadas), total))
+}
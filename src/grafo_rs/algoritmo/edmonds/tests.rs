@@ -0,0 +1,163 @@
+use crate::grafo_rs::{Arista, Grafo, NoPeso};
+use super::*;
+
+///
+/// Mayor peso de un emparejamiento entre los vertices 0..n probando todas las opciones. Sin pesos cuenta aristas
+/// 
+fn fuerza_bruta(n: usize, pesos: &[(usize, usize, isize)], libres: &mut Vec<bool>) -> isize
+{
+    let Some(v) = (0..n).find(|v| libres[*v]) else { return 0; };
+    libres[v] = false;
+    let mut mejor = fuerza_bruta(n, pesos, libres);
+    for (i, j, peso) in pesos.iter().copied()
+    {
+        let otro = match (i == v, j == v) {
+            (true, false) => j,
+            (false, true) => i,
+            _ => continue
+        };
+        if libres[otro]
+        {
+            libres[otro] = false;
+            mejor = mejor.max(peso + fuerza_bruta(n, pesos, libres));
+            libres[otro] = true;
+        }
+    }
+    libres[v] = true;
+    mejor
+}
+
+///
+/// Aristas pseudoaleatorias entre los vertices 0..n con pesos entre 1 y 20
+/// 
+fn aleatorio(n: usize, semilla: usize) -> Vec<(usize, usize, isize)>
+{
+    let mut estado = (semilla * 2654435761 + 12345) % 2147483648;
+    let mut siguiente = || {
+        estado = (estado * 1103515245 + 12345) % 2147483648;
+        estado
+    };
+    let mut pesos = vec![];
+    for i in 0..n
+    {
+        for j in i + 1..n
+        {
+            if siguiente() % 100 < 45
+            {
+                pesos.push((i, j, (siguiente() % 20 + 1) as isize));
+            }
+        }
+    }
+    pesos
+}
+
+fn grafo(pesos: &[(usize, usize, isize)]) -> Grafo<usize, isize>
+{
+    Grafo::from_aristas(pesos.iter().map(|(i, j, peso)| Arista::arista(*i, *j, Some(*peso))).collect())
+}
+
+#[test]
+fn test_emparejamiento_maximo()
+{
+    // Ciclo de cinco con una cola: hace falta contraer la flor para saturar la cola
+    let g: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(1, 2),
+                                                       Arista::arista_sin_peso(2, 3),
+                                                       Arista::arista_sin_peso(3, 4),
+                                                       Arista::arista_sin_peso(4, 5),
+                                                       Arista::arista_sin_peso(5, 1),
+                                                       Arista::arista_sin_peso(5, 6),
+                                                       Arista::arista_sin_peso(6, 7),
+                                                       Arista::arista_sin_peso(2, 2)].to_vec());
+    let emparejamiento = emparejamiento_maximo(&g);
+    assert_eq!(emparejamiento.size(), 3);
+    assert!(emparejamiento.get_aristas().iter().all(|x| g.get_aristas().contains(x)));
+
+    // Grafo de Petersen: tiene emparejamiento perfecto
+    let mut aristas = vec![];
+    for i in 0..5
+    {
+        aristas.push(Arista::arista_sin_peso(i, (i + 1) % 5));
+        aristas.push(Arista::arista_sin_peso(i, i + 5));
+        aristas.push(Arista::arista_sin_peso(i + 5, (i + 2) % 5 + 5));
+    }
+    let petersen: Grafo<i32, NoPeso> = Grafo::from_aristas(aristas);
+    assert_eq!(emparejamiento_maximo(&petersen).size(), 5);
+
+    assert_eq!(emparejamiento_maximo(&Grafo::<i32, NoPeso>::new()).size(), 0);
+}
+
+#[test]
+fn test_emparejamiento_maximo_fuerza_bruta()
+{
+    for semilla in 0..40
+    {
+        let pesos: Vec<(usize, usize, isize)> = aleatorio(9, semilla).into_iter().map(|(i, j, _)| (i, j, 1)).collect();
+        assert_eq!(emparejamiento_maximo(&grafo(&pesos)).size() as isize,
+                   fuerza_bruta(9, &pesos, &mut vec![true; 9]));
+    }
+}
+
+#[test]
+fn test_emparejamiento_peso_maximo()
+{
+    let g = grafo(&[(1, 2, 5), (2, 3, 11), (3, 4, 5)]);
+    let (emparejamiento, peso) = emparejamiento_peso_maximo(&g).unwrap();
+    assert_eq!((emparejamiento.size(), peso), (1, 11));
+
+    // Flor exterior usada para aumentar
+    let g = grafo(&[(1, 2, 8), (1, 3, 9), (2, 3, 10), (3, 4, 7), (1, 6, 5), (4, 5, 6)]);
+    let (emparejamiento, peso) = emparejamiento_peso_maximo(&g).unwrap();
+    assert_eq!((emparejamiento.size(), peso), (3, 21));
+    assert_eq!(emparejamiento.recorrer(&2), Some(&3));
+
+    // Flor anidada que se reetiqueta como interior y se expande
+    let g = grafo(&[(1, 2, 19), (1, 3, 20), (1, 8, 8), (2, 3, 25), (2, 4, 18), (3, 5, 18), (4, 5, 13), (4, 7, 7),
+                    (5, 6, 7)]);
+    let (emparejamiento, peso) = emparejamiento_peso_maximo(&g).unwrap();
+    assert_eq!((emparejamiento.size(), peso), (4, 47));
+    assert_eq!(emparejamiento.recorrer(&1), Some(&8));
+
+    // Expansion de una flor interior cuya base no es la entrada
+    let g = grafo(&[(1, 2, 45), (1, 5, 45), (2, 3, 50), (3, 4, 45), (4, 5, 50), (1, 6, 30), (3, 9, 35), (4, 8, 35),
+                    (5, 7, 26), (9, 10, 5)]);
+    let (emparejamiento, peso) = emparejamiento_peso_maximo(&g).unwrap();
+    assert_eq!((emparejamiento.size(), peso), (5, 146));
+}
+
+#[test]
+fn test_emparejamiento_peso_maximo_fuerza_bruta()
+{
+    for semilla in 0..60
+    {
+        let pesos = aleatorio(10, semilla);
+        let g = grafo(&pesos);
+        let (emparejamiento, peso) = emparejamiento_peso_maximo(&g).unwrap();
+        assert_eq!(peso, fuerza_bruta(10, &pesos, &mut vec![true; 10]));
+        assert_eq!(emparejamiento.get_aristas().iter().map(|x| *x.get_peso().unwrap()).sum::<isize>(), peso);
+    }
+}
+
+#[test]
+fn test_emparejamiento_peso_maximo_especiales()
+{
+    // Aristas paralelas, lazos y pesos no positivos
+    let g: Grafo<i32, isize> = Grafo::from_aristas([Arista::arista(1, 2, Some(3)),
+                                                      Arista::arista(1, 2, Some(7)),
+                                                      Arista::arista(2, 2, Some(50)),
+                                                      Arista::arista(3, 4, Some(-2)),
+                                                      Arista::arista(3, 4, Some(0))].to_vec());
+    let (emparejamiento, peso) = emparejamiento_peso_maximo(&g).unwrap();
+    assert_eq!((emparejamiento.size(), peso), (1, 7));
+    assert!(emparejamiento.es_libre(&3));
+
+    let sin_peso: Grafo<i32, isize> = Grafo::from_aristas([Arista::arista(1, 2, None)].to_vec());
+    assert!(emparejamiento_peso_maximo(&sin_peso).is_none());
+
+    // Los vertices aislados no necesitan peso
+    let aislado: Grafo<i32, isize> = Grafo::from_aristas([Arista::arista(1, 2, Some(5)), Arista::vertice(9)].to_vec());
+    let (emparejamiento, peso) = emparejamiento_peso_maximo(&aislado).expect("Todas las aristas tienen peso");
+    assert_eq!((emparejamiento.size(), peso), (1, 5));
+    assert!(emparejamiento.es_libre(&9));
+    let vacio: Grafo<i32, isize> = Grafo::new();
+    assert_eq!(emparejamiento_peso_maximo(&vacio).unwrap().1, 0);
+}
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+use crate::grafo_rs::{Arista, AristaT, Diarista, Digrafo, Grafo, GrafoT, PesoT, VerticeT};
+
+#[cfg(test)]
+mod tests;
+
+///
+/// Funcion auxiliar. Algoritmo de Hierholzer desde el vertice inicial sobre una lista de aristas,
+/// que puede contener aristas repetidas
+/// POST: Aristas del recorrido en orden. Solo se recorren las aristas alcanzables desde el inicio
+/// NOTA: Los lazos no dirigidos aparecen una unica vez en la lista de adyacencia, por lo que se recorren una vez
+/// 
+pub(crate) fn hierholzer<'a, Arista, Vertice, Peso>(aristas: &[&'a Arista], inicio: &Vertice) -> Vec<&'a Arista>
+where Arista: AristaT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
+{
+    // Identificadores de los extremos y posiciones de las aristas que pueden recorrerse desde cada uno
+    let mut ids: HashMap<&Vertice, usize> = HashMap::new();
+    let mut adyacencia: Vec<Vec<usize>> = vec![];
+    for (pos, arista) in aristas.iter().enumerate()
+    {
+        if let Some((v, w)) = arista.get_vertices()
+        {
+            for u in [v, w]
+            {
+                if !ids.contains_key(u)
+                {
+                    ids.insert(u, adyacencia.len());
+                    adyacencia.push(vec![]);
+                }
+            }
+            if arista.es_accesible(v)
+            {
+                adyacencia[ids[v]].push(pos);
+            }
+            if v != w && arista.es_accesible(w)
+            {
+                adyacencia[ids[w]].push(pos);
+            }
+        }
+    }
+    let inicio = match ids.get_key_value(inicio) {
+        Some((v, _)) => *v,
+        None => { return vec![]; }
+    };
+
+    let mut aristas_usadas = vec![false; aristas.len()];
+    let mut siguiente = vec![0; adyacencia.len()];
+    let mut camino = vec![];
+    // Pila con el vertice actual y la arista por la que se llego a el
+    let mut pila: Vec<(&Vertice, Option<usize>)> = vec![(inicio, None)];
+
+    while let Some((v, pos_previa)) = pila.last().copied()
+    {
+        let id = ids[v];
+        while siguiente[id] < adyacencia[id].len() && aristas_usadas[adyacencia[id][siguiente[id]]]
+        {
+            siguiente[id] += 1;
+        }
+        match adyacencia[id].get(siguiente[id]) {
+            Some(pos) => {
+                aristas_usadas[*pos] = true;
+                pila.push((aristas[*pos].other(v).unwrap(), Some(*pos)));
+            },
+            None => {
+                // Sin aristas libres: el vertice se cierra y su arista pasa al recorrido
+                pila.pop();
+                if let Some(pos) = pos_previa
+                {
+                    camino.push(aristas[pos]);
+                }
+            }
+        }
+    }
+    camino.reverse();
+    camino
+}
+
+///
+/// Funcion auxiliar. Comprueba que el recorrido use todas las aristas del grafo
+/// 
+fn recorrido_completo<'a, Graf, Vertice, Peso>(grafo: &'a Graf, inicio: Option<&Vertice>) -> Option<Vec<&'a Graf::Arista>>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
+{
+    let num_aristas = grafo.get_aristas().iter().filter(|x| x.get_vertices().is_some()).count();
+    let camino = match inicio {
+        Some(v) => hierholzer(&grafo.get_aristas().iter().collect::<Vec<_>>(), v),
+        None => vec![]
+    };
+    match camino.len() == num_aristas {
+        true => Some(camino),
+        false => None
+    }
+}
+
+///
+/// PRE: Grafo
+/// POST: Aristas de un camino que recorre cada arista exactamente una vez, en orden. Si existe un circuito
+/// euleriano, se devuelve este. None si no existe
+/// NOTA: Algoritmo de Hierholzer. Los vertices aislados se ignoran y los lazos cuentan dos veces en el grado
+/// 
+pub fn camino_euleriano<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>) -> Option<Vec<&Arista<Vertice, Peso>>>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let vertices = grafo.get_vertices();
+    let impares: Vec<&Vertice> = vertices.iter()
+                        .filter(|v| grafo.grado(v).unwrap() % 2 == 1)
+                        .copied()
+                        .collect();
+    let inicio = match impares.len() {
+        0 => vertices.into_iter().find(|v| grafo.grado(v).unwrap() > 0),
+        2 => Some(impares[0]),
+        _ => { return None; }
+    };
+    recorrido_completo(grafo, inicio)
+}
+
+///
+/// PRE: Grafo
+/// POST: Aristas de un circuito que recorre cada arista exactamente una vez, en orden. None si no existe
+/// NOTA: Algoritmo de Hierholzer. Los vertices aislados se ignoran y los lazos cuentan dos veces en el grado
+/// 
+pub fn circuito_euleriano<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>) -> Option<Vec<&Arista<Vertice, Peso>>>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let vertices = grafo.get_vertices();
+    if vertices.iter().any(|v| grafo.grado(v).unwrap() % 2 == 1)
+    {
+        return None;
+    }
+    let inicio = vertices.into_iter().find(|v| grafo.grado(v).unwrap() > 0);
+    recorrido_completo(grafo, inicio)
+}
+
+///
+/// PRE: Digrafo
+/// POST: Arcos de un camino que recorre cada arco exactamente una vez respetando su sentido, en orden.
+/// Si existe un circuito euleriano, se devuelve este. None si no existe
+/// NOTA: Algoritmo de Hierholzer. Los vertices aislados se ignoran
+/// 
+pub fn camino_euleriano_dirigido<Vertice, Peso>(digrafo: &Digrafo<Vertice, Peso>) -> Option<Vec<&Diarista<Vertice, Peso>>>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let vertices = digrafo.get_vertices();
+    let mut inicio = None;
+    let mut fin = None;
+    for v in vertices.iter()
+    {
+        let salida = digrafo.grado_salida(v).unwrap() as isize;
+        let entrada = digrafo.grado_entrada(v).unwrap() as isize;
+        match salida - entrada {
+            0 => {},
+            1 if inicio.is_none() => { inicio = Some(*v); },
+            -1 if fin.is_none() => { fin = Some(*v); },
+            _ => { return None; }
+        }
+    }
+    if inicio.is_none() != fin.is_none()
+    {
+        return None;
+    }
+    let inicio = inicio.or_else(|| vertices.into_iter().find(|v| digrafo.grado_salida(v).unwrap() > 0));
+    recorrido_completo(digrafo, inicio)
+}
+
+///
+/// PRE: Digrafo
+/// POST: Arcos de un circuito que recorre cada arco exactamente una vez respetando su sentido, en orden.
+/// None si no existe
+/// NOTA: Algoritmo de Hierholzer. Los vertices aislados se ignoran
+/// 
+pub fn circuito_euleriano_dirigido<Vertice, Peso>(digrafo: &Digrafo<Vertice, Peso>) -> Option<Vec<&Diarista<Vertice, Peso>>>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let vertices = digrafo.get_vertices();
+    if vertices.iter().any(|v| digrafo.grado_salida(v) != digrafo.grado_entrada(v))
+    {
+        return None;
+    }
+    let inicio = vertices.into_iter().find(|v| digrafo.grado_salida(v).unwrap() > 0);
+    recorrido_completo(digrafo, inicio)
+}
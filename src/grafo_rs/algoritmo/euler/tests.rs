@@ -0,0 +1,99 @@
+use crate::grafo_rs::{Arista, Diarista, AristaT, Grafo, Digrafo, GrafoT, NoPeso};
+use super::*;
+
+///
+/// Comprueba que las aristas formen un recorrido desde el vertice dado y devuelve el vertice final
+/// 
+fn recorrer<A>(inicio: i32, camino: &[&A]) -> i32
+where A: AristaT<i32, NoPeso>
+{
+    let mut actual = inicio;
+    for arista in camino
+    {
+        assert!(arista.es_accesible(&actual), "La arista debe poder recorrerse desde {}", actual);
+        actual = *arista.other(&actual).unwrap();
+    }
+    actual
+}
+
+#[test]
+fn test_euler_grafo()
+{
+    // Cuadrado con una diagonal: 1 y 3 tienen grado impar
+    let g: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(1, 2),
+                                                        Arista::arista_sin_peso(2, 3),
+                                                        Arista::arista_sin_peso(3, 4),
+                                                        Arista::arista_sin_peso(4, 1),
+                                                        Arista::arista_sin_peso(1, 3),
+                                                        Arista::vertice(10)].to_vec());
+    assert!(circuito_euleriano(&g).is_none());
+    let camino = camino_euleriano(&g).expect("Existe camino euleriano");
+    assert_eq!(camino.len(), 5);
+    assert_eq!(recorrer(1, &camino), 3);
+
+    // Triangulo con un lazo: el lazo suma dos al grado y se recorre una vez
+    let g: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(1, 2),
+                                                        Arista::arista_sin_peso(2, 3),
+                                                        Arista::arista_sin_peso(3, 1),
+                                                        Arista::arista_sin_peso(2, 2)].to_vec());
+    let circuito = circuito_euleriano(&g).expect("Existe circuito euleriano");
+    assert_eq!(circuito.len(), 4);
+    assert!(circuito.contains(&&Arista::arista_sin_peso(2, 2)));
+    assert_eq!(recorrer(1, &circuito), 1);
+    assert_eq!(camino_euleriano(&g).map(|x| x.len()), Some(4), "Un circuito es tambien un camino");
+}
+
+#[test]
+fn test_euler_grafo_casos_negativos()
+{
+    // Estrella con cuatro vertices de grado impar
+    let estrella: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(0, 1),
+                                                            Arista::arista_sin_peso(0, 2),
+                                                            Arista::arista_sin_peso(0, 3)].to_vec());
+    assert!(camino_euleriano(&estrella).is_none());
+
+    // Dos triangulos disjuntos: grados pares pero las aristas no son conexas
+    let g: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(1, 2),
+                                                        Arista::arista_sin_peso(2, 3),
+                                                        Arista::arista_sin_peso(3, 1),
+                                                        Arista::arista_sin_peso(4, 5),
+                                                        Arista::arista_sin_peso(5, 6),
+                                                        Arista::arista_sin_peso(6, 4)].to_vec());
+    assert!(circuito_euleriano(&g).is_none());
+    assert!(camino_euleriano(&g).is_none());
+
+    // Sin aristas el recorrido es vacio
+    let g: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::vertice(1)].to_vec());
+    assert_eq!(circuito_euleriano(&g).map(|x| x.len()), Some(0));
+}
+
+#[test]
+fn test_euler_digrafo()
+{
+    let digrafo: Digrafo<i32> = Digrafo::from_aristas([Diarista::arista_sin_peso(1, 2),
+                                                            Diarista::arista_sin_peso(2, 3),
+                                                            Diarista::arista_sin_peso(3, 1),
+                                                            Diarista::arista_sin_peso(1, 4),
+                                                            Diarista::arista_sin_peso(4, 1),
+                                                            Diarista::arista_sin_peso(4, 4),
+                                                            Diarista::vertice(10)].to_vec());
+    let circuito = circuito_euleriano_dirigido(&digrafo).expect("Existe circuito euleriano");
+    assert_eq!(circuito.len(), 6);
+    assert_eq!(recorrer(1, &circuito), 1);
+
+    // Al eliminar un arco el camino debe empezar en el vertice con exceso de salida
+    let mut camino_abierto = digrafo.clone();
+    camino_abierto.remove_arista(&Diarista::arista_sin_peso(1, 2));
+    assert!(circuito_euleriano_dirigido(&camino_abierto).is_none());
+    let camino = camino_euleriano_dirigido(&camino_abierto).expect("Existe camino euleriano");
+    assert_eq!(camino.len(), 5);
+    assert_eq!(recorrer(2, &camino), 1);
+
+    // El grafo subyacente de un triangulo es euleriano aunque el digrafo no lo sea
+    let digrafo: Digrafo<i32> = Digrafo::from_aristas([Diarista::arista_sin_peso(1, 2),
+                                                            Diarista::arista_sin_peso(2, 3),
+                                                            Diarista::arista_sin_peso(1, 3)].to_vec());
+    assert!(circuito_euleriano(&digrafo.grafo_subyacente()).is_some());
+    assert!(circuito_euleriano_dirigido(&digrafo).is_none());
+    assert!(camino_euleriano_dirigido(&digrafo).is_none());
+}
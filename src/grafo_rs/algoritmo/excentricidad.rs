@@ -0,0 +1,222 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+use crate::grafo_rs::{AristaT, Etiquetado, GrafoT, PesoT, VerticeT};
+
+#[cfg(test)]
+mod tests;
+
+///
+/// Metricas de distancia de un grafo: excentricidad de cada vertice, diametro, radio, centro y periferia
+/// 
+pub struct AnalisisDistancias<Vertice>
+where Vertice: VerticeT
+{
+    excentricidades: Etiquetado<Vertice>,
+    diametro: isize,
+    radio: isize
+}
+
+impl<Vertice> AnalisisDistancias<Vertice>
+where Vertice: VerticeT
+{
+    ///
+    /// POST: Analisis a partir de las excentricidades, en el orden de los vertices del grafo
+    /// 
+    fn new(vertices: Vec<&Vertice>, excentricidades: Vec<isize>) -> Self
+    {
+        let diametro = excentricidades.iter().copied().max().unwrap();
+        let radio = excentricidades.iter().copied().min().unwrap();
+        let mut etiquetado = Etiquetado::new(Some("Excentricidad"));
+        for (v, e) in vertices.into_iter().zip(excentricidades)
+        {
+            etiquetado.add_vertice(v.clone(), e);
+        }
+        Self { excentricidades: etiquetado, diametro, radio }
+    }
+
+    ///
+    /// POST: Etiquetado con la mayor distancia desde cada vertice a cualquier otro
+    /// 
+    pub fn get_excentricidades(&self) -> &Etiquetado<Vertice>
+    {
+        &self.excentricidades
+    }
+
+    ///
+    /// POST: Diametro: mayor excentricidad
+    /// 
+    pub fn get_diametro(&self) -> isize
+    {
+        self.diametro
+    }
+
+    ///
+    /// POST: Radio: menor excentricidad
+    /// 
+    pub fn get_radio(&self) -> isize
+    {
+        self.radio
+    }
+
+    ///
+    /// POST: Vertices de excentricidad igual al radio
+    /// 
+    pub fn get_centro(&self) -> Vec<&Vertice>
+    {
+        self.con_excentricidad(self.radio)
+    }
+
+    ///
+    /// POST: Vertices de excentricidad igual al diametro
+    /// 
+    pub fn get_periferia(&self) -> Vec<&Vertice>
+    {
+        self.con_excentricidad(self.diametro)
+    }
+
+    ///
+    /// POST: Vertices cuya excentricidad es igual a valor
+    /// 
+    fn con_excentricidad(&self, valor: isize) -> Vec<&Vertice>
+    {
+        self.excentricidades.get_datos().iter()
+            .filter(|x| x.get_valor() == valor)
+            .map(|x| x.get_vertice())
+            .collect()
+    }
+}
+
+impl<Vertice> Clone for AnalisisDistancias<Vertice>
+where Vertice: VerticeT
+{
+    fn clone(&self) -> Self {
+        Self {
+            excentricidades: self.excentricidades.clone(),
+            diametro: self.diametro,
+            radio: self.radio
+        }
+    }
+}
+
+///
+/// PRE: Grafo o digrafo
+/// POST: Excentricidades, diametro, radio, centro y periferia contando el numero de aristas de cada camino.
+/// None si el grafo esta vacio o algun vertice no alcanza a todos los demas
+/// NOTA: Busqueda en anchura desde cada vertice. O(n m). En un Digrafo se usan las distancias desde cada vertice
+/// 
+pub fn analisis_distancias<Graf, Vertice, Peso>(grafo: &Graf) -> Option<AnalisisDistancias<Vertice>>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
+{
+    let vertices = grafo.get_vertices();
+    if vertices.is_empty()
+    {
+        return None;
+    }
+    let ids: HashMap<&Vertice, usize> = vertices.iter().enumerate()
+                                            .map(|(i, v)| (*v, i))
+                                            .collect();
+    let adyacencia: Vec<Vec<usize>> = vertices.iter()
+                        .map(|v| grafo.aristas_accesibles(v).into_iter().map(|x| ids[x.other(v).unwrap()]).collect())
+                        .collect();
+
+    let mut excentricidades = vec![];
+    for origen in 0..vertices.len()
+    {
+        let mut niveles: Vec<Option<isize>> = vec![None; vertices.len()];
+        niveles[origen] = Some(0);
+        let mut cola = VecDeque::from([origen]);
+        let mut alcanzados = 1;
+        let mut excentricidad = 0;
+        while let Some(v) = cola.pop_front()
+        {
+            let nivel = niveles[v].unwrap();
+            excentricidad = nivel;
+            for w in adyacencia[v].iter()
+            {
+                if niveles[*w].is_none()
+                {
+                    niveles[*w] = Some(nivel + 1);
+                    alcanzados += 1;
+                    cola.push_back(*w);
+                }
+            }
+        }
+        if alcanzados < vertices.len()
+        {
+            return None;
+        }
+        excentricidades.push(excentricidad);
+    }
+    Some(AnalisisDistancias::new(vertices, excentricidades))
+}
+
+///
+/// PRE: Grafo o digrafo con pesos no negativos
+/// POST: Excentricidades, diametro, radio, centro y periferia usando los pesos como longitudes.
+/// None si el grafo esta vacio, algun vertice no alcanza a todos los demas o falta algun peso o es negativo
+/// NOTA: Algoritmo de Dijkstra desde cada vertice. O(n m log n). Las distancias se convierten con `PesoT::to_isize`.
+/// En un Digrafo se usan las distancias desde cada vertice
+/// 
+pub fn analisis_distancias_ponderado<Graf, Vertice, Peso>(grafo: &Graf) -> Option<AnalisisDistancias<Vertice>>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT + Ord
+{
+    let vertices = grafo.get_vertices();
+    if vertices.is_empty()
+    {
+        return None;
+    }
+    let ids: HashMap<&Vertice, usize> = vertices.iter().enumerate()
+                                            .map(|(i, v)| (*v, i))
+                                            .collect();
+    let mut adyacencia: Vec<Vec<(usize, &Peso)>> = vec![vec![]; vertices.len()];
+    for (id, v) in vertices.iter().enumerate()
+    {
+        for arista in grafo.aristas_accesibles(v)
+        {
+            let peso = arista.get_peso()?;
+            if peso.es_negativo()
+            {
+                return None;
+            }
+            adyacencia[id].push((ids[arista.other(v).unwrap()], peso));
+        }
+    }
+
+    let mut excentricidades = vec![];
+    for origen in 0..vertices.len()
+    {
+        let mut distancias: Vec<Option<Peso>> = vec![None; vertices.len()];
+        let mut visitados = vec![false; vertices.len()];
+        let mut cola = BinaryHeap::from([Reverse((Peso::elemento_neutro(), origen))]);
+        let mut alcanzados = 0;
+        let mut excentricidad = Peso::elemento_neutro();
+        distancias[origen] = Some(Peso::elemento_neutro());
+        while let Some(Reverse((acarreo, v))) = cola.pop()
+        {
+            // Las entradas obsoletas de la cola se descartan
+            if visitados[v]
+            {
+                continue;
+            }
+            visitados[v] = true;
+            alcanzados += 1;
+            for (w, peso) in adyacencia[v].iter()
+            {
+                let nueva_distancia = acarreo.suma(peso);
+                if !visitados[*w] && distancias[*w].as_ref().is_none_or(|d| *d > nueva_distancia)
+                {
+                    distancias[*w] = Some(nueva_distancia.clone());
+                    cola.push(Reverse((nueva_distancia, *w)));
+                }
+            }
+            excentricidad = acarreo;
+        }
+        if alcanzados < vertices.len()
+        {
+            return None;
+        }
+        excentricidades.push(excentricidad.to_isize());
+    }
+    Some(AnalisisDistancias::new(vertices, excentricidades))
+}
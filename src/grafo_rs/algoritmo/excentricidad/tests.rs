@@ -0,0 +1,76 @@
+use crate::grafo_rs::{Arista, Diarista, Grafo, Digrafo, GrafoT, NoPeso, arbol_camino_minimo};
+use super::*;
+
+#[test]
+fn test_analisis_distancias()
+{
+    let camino: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(1, 2),
+                                                            Arista::arista_sin_peso(2, 3),
+                                                            Arista::arista_sin_peso(3, 4),
+                                                            Arista::arista_sin_peso(4, 5)].to_vec());
+    let analisis = analisis_distancias(&camino).expect("Grafo conexo");
+    assert_eq!(analisis.get_diametro(), 4);
+    assert_eq!(analisis.get_radio(), 2);
+    assert_eq!(analisis.get_centro(), vec![&3]);
+    let mut periferia = analisis.get_periferia();
+    periferia.sort();
+    assert_eq!(periferia, vec![&1, &5]);
+    assert_eq!(analisis.get_excentricidades().get_nombre(), Some("Excentricidad"));
+    assert_eq!(analisis.get_excentricidades().buscar_vertice(&2).unwrap().get_valor(), 3);
+
+    let disconexo: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(1, 2),
+                                                              Arista::vertice(3)].to_vec());
+    assert!(analisis_distancias(&disconexo).is_none());
+    assert!(analisis_distancias(&Grafo::<i32, NoPeso>::new()).is_none());
+}
+
+#[test]
+fn test_analisis_distancias_digrafo()
+{
+    let mut ciclo: Digrafo<i32, isize> = Digrafo::from_aristas([Diarista::arista(1, 2, Some(1)),
+                                                                  Diarista::arista(2, 3, Some(1)),
+                                                                  Diarista::arista(3, 4, Some(1)),
+                                                                  Diarista::arista(4, 1, Some(5))].to_vec());
+    let analisis = analisis_distancias(&ciclo).expect("Fuertemente conexo");
+    assert_eq!((analisis.get_radio(), analisis.get_diametro()), (3, 3));
+    assert_eq!(analisis.get_centro().len(), 4);
+
+    // Con pesos, el arco 4 -> 1 es largo
+    let analisis = analisis_distancias_ponderado(&ciclo).expect("Fuertemente conexo");
+    assert_eq!(analisis.get_excentricidades().buscar_vertice(&1).unwrap().get_valor(), 3);
+    assert_eq!(analisis.get_excentricidades().buscar_vertice(&2).unwrap().get_valor(), 7);
+    assert_eq!(analisis.get_radio(), 3);
+    assert_eq!(analisis.get_diametro(), 7);
+    assert_eq!(analisis.get_centro(), vec![&1]);
+    assert_eq!(analisis.get_periferia().len(), 3);
+
+    ciclo.remove_arista(&Diarista::arista(4, 1, Some(5)));
+    assert!(analisis_distancias(&ciclo).is_none());
+    assert!(analisis_distancias_ponderado(&ciclo).is_none());
+}
+
+#[test]
+fn test_analisis_distancias_ponderado()
+{
+    // Se compara con el maximo de las distancias de Dijkstra desde cada vertice
+    let mut aristas = vec![];
+    for i in 0..12
+    {
+        aristas.push(Arista::arista(i, (i + 1) % 12, Some((i * 7 % 5 + 1) as isize)));
+        aristas.push(Arista::arista(i, (i * 5 + 3) % 12, Some((i * 3 % 7 + 2) as isize)));
+    }
+    let g: Grafo<i32, isize> = Grafo::from_aristas(aristas);
+    let analisis = analisis_distancias_ponderado(&g).expect("Grafo conexo con pesos");
+    for v in g.get_vertices()
+    {
+        let (_, distancias) = arbol_camino_minimo(&g, v).unwrap();
+        assert_eq!(analisis.get_excentricidades().buscar_vertice(v).unwrap().get_valor(),
+                   distancias.max().unwrap().get_valor());
+    }
+
+    let negativo: Grafo<i32, isize> = Grafo::from_aristas([Arista::arista(1, 2, Some(-1))].to_vec());
+    assert!(analisis_distancias_ponderado(&negativo).is_none());
+    let sin_peso: Grafo<i32, isize> = Grafo::from_aristas([Arista::arista(1, 2, None)].to_vec());
+    assert!(analisis_distancias_ponderado(&sin_peso).is_none());
+    assert!(analisis_distancias(&sin_peso).is_some());
+}
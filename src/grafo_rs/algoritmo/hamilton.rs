@@ -0,0 +1,401 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::time::{Duration, Instant};
+
+use crate::grafo_rs::{AristaT, GrafoT, PesoT, VerticeT};
+
+#[cfg(test)]
+mod tests;
+
+///
+/// Numero maximo de vertices para el metodo de Held-Karp
+/// 
+pub const LIMITE_HELD_KARP: usize = 20;
+
+///
+/// Resultado de la busqueda de un camino o ciclo hamiltoniano
+/// 
+pub enum ResultadoHamilton<'a, Vertice>
+{
+    /// Vertices del camino o ciclo en orden. En un ciclo el ultimo vertice se une con el primero
+    Encontrado(Vec<&'a Vertice>),
+    /// Se ha comprobado que no existe
+    Inexistente,
+    /// Se ha agotado el presupuesto antes de terminar
+    Desconocido
+}
+
+impl<'a, Vertice> Debug for ResultadoHamilton<'a, Vertice>
+where Vertice: Debug
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResultadoHamilton::Encontrado(vertices) => write!(f, "Encontrado({:?})", vertices),
+            ResultadoHamilton::Inexistente => write!(f, "Inexistente"),
+            ResultadoHamilton::Desconocido => write!(f, "Desconocido")
+        }
+    }
+}
+
+///
+/// Metodo de busqueda de caminos y ciclos hamiltonianos
+/// 
+pub enum MetodoHamilton
+{
+    /// Vuelta atras con poda por grados
+    Backtracking,
+    /// Programacion dinamica sobre subconjuntos. Solo para grafos de hasta [`LIMITE_HELD_KARP`] vertices
+    HeldKarp
+}
+
+///
+/// Limites de una busqueda: numero de nodos explorados y tiempo de ejecucion. None indica sin limite
+/// 
+pub struct Presupuesto
+{
+    nodos: Option<usize>,
+    tiempo: Option<Duration>
+}
+
+impl Presupuesto
+{
+    ///
+    /// PRE: Numero maximo de nodos y tiempo maximo, si los hay
+    /// POST: Presupuesto
+    /// 
+    pub fn new(nodos: Option<usize>, tiempo: Option<Duration>) -> Self
+    {
+        Self { nodos, tiempo }
+    }
+
+    ///
+    /// POST: Presupuesto sin limites
+    /// 
+    pub fn ilimitado() -> Self
+    {
+        Self::new(None, None)
+    }
+
+    ///
+    /// POST: Numero maximo de nodos explorados. None si no hay limite
+    /// 
+    pub fn get_nodos(&self) -> Option<usize>
+    {
+        self.nodos
+    }
+
+    ///
+    /// POST: Tiempo maximo de ejecucion. None si no hay limite
+    /// 
+    pub fn get_tiempo(&self) -> Option<Duration>
+    {
+        self.tiempo
+    }
+}
+
+impl Clone for Presupuesto
+{
+    fn clone(&self) -> Self {
+        Self::new(self.nodos, self.tiempo)
+    }
+}
+
+///
+/// Estructura auxiliar con la representacion densa del grafo y el consumo del presupuesto
+/// 
+struct Busqueda<'p>
+{
+    sucesores: Vec<Vec<usize>>,
+    predecesores: Vec<Vec<usize>>,
+    presupuesto: &'p Presupuesto,
+    nodos: usize,
+    inicio: Instant
+}
+
+impl<'p> Busqueda<'p>
+{
+    ///
+    /// POST: Busqueda sobre el grafo, junto con sus vertices en orden de identificador
+    /// 
+    fn new<'a, Graf, Vertice, Peso>(grafo: &'a Graf, presupuesto: &'p Presupuesto) -> (Self, Vec<&'a Vertice>)
+    where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
+    {
+        let vertices = grafo.get_vertices();
+        let ids: HashMap<&Vertice, usize> = vertices.iter().enumerate()
+                                                .map(|(i, v)| (*v, i))
+                                                .collect();
+        let mut sucesores: Vec<Vec<usize>> = vec![vec![]; vertices.len()];
+        let mut predecesores: Vec<Vec<usize>> = vec![vec![]; vertices.len()];
+        for (id, v) in vertices.iter().enumerate()
+        {
+            for arista in grafo.aristas_accesibles(v)
+            {
+                let w = ids[arista.other(v).unwrap()];
+                if w != id && !sucesores[id].contains(&w)
+                {
+                    sucesores[id].push(w);
+                    predecesores[w].push(id);
+                }
+            }
+        }
+        let busqueda = Self {
+            sucesores,
+            predecesores,
+            presupuesto,
+            nodos: 0,
+            inicio: Instant::now()
+        };
+        (busqueda, vertices)
+    }
+
+    ///
+    /// POST: Cuenta un nodo explorado. true si se ha agotado el presupuesto
+    /// 
+    fn consumir(&mut self) -> bool
+    {
+        self.nodos += 1;
+        self.presupuesto.nodos.is_some_and(|max| self.nodos > max)
+            || self.presupuesto.tiempo.is_some_and(|max| self.inicio.elapsed() > max)
+    }
+
+    ///
+    /// POST: true si la eleccion de vertices del camino parcial no puede completarse
+    /// NOTA: Cada vertice no visitado debe poder alcanzarse desde otro no visitado o desde el actual y,
+    /// salvo uno en los caminos, debe poder abandonarse
+    /// 
+    fn podar(&self, camino: &[usize], visitados: &[bool], ciclo: bool) -> bool
+    {
+        let actual = *camino.last().unwrap();
+        let mut finales = 0;
+        for x in (0..visitados.len()).filter(|x| !visitados[*x])
+        {
+            if !self.predecesores[x].iter().any(|u| !visitados[*u] || *u == actual)
+            {
+                return true;
+            }
+            if !self.sucesores[x].iter().any(|w| !visitados[*w] || (ciclo && *w == camino[0]))
+            {
+                finales += 1;
+                if ciclo || finales > 1
+                {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    ///
+    /// POST: Some(true) si el camino parcial se completa, Some(false) si no puede completarse y None si se agota
+    /// el presupuesto
+    /// 
+    fn extender(&mut self, camino: &mut Vec<usize>, visitados: &mut [bool], ciclo: bool) -> Option<bool>
+    {
+        if self.consumir()
+        {
+            return None;
+        }
+        let actual = *camino.last().unwrap();
+        if camino.len() == visitados.len()
+        {
+            return Some(!ciclo || self.sucesores[actual].contains(&camino[0]));
+        }
+        if self.podar(camino, visitados, ciclo)
+        {
+            return Some(false);
+        }
+
+        // Se prueban primero los vertices con menos salidas libres
+        let mut candidatos: Vec<(usize, usize)> = self.sucesores[actual].iter()
+                            .filter(|w| !visitados[**w])
+                            .map(|w| (self.sucesores[*w].iter().filter(|x| !visitados[**x]).count(), *w))
+                            .collect();
+        candidatos.sort();
+        for (_, w) in candidatos
+        {
+            visitados[w] = true;
+            camino.push(w);
+            if self.extender(camino, visitados, ciclo)?
+            {
+                return Some(true);
+            }
+            camino.pop();
+            visitados[w] = false;
+        }
+        Some(false)
+    }
+
+    ///
+    /// POST: Identificadores del camino o ciclo por vuelta atras, con su estado
+    /// 
+    fn backtracking(&mut self, ciclo: bool) -> Option<Option<Vec<usize>>>
+    {
+        let n = self.sucesores.len();
+        // Si algun vertice no tiene predecesores, el camino debe empezar en el
+        let iniciales: Vec<usize> = match (0..n).find(|v| self.predecesores[*v].is_empty()) {
+            Some(v) if !ciclo => vec![v],
+            _ if ciclo => vec![0],
+            _ => (0..n).collect()
+        };
+        for v in iniciales
+        {
+            let mut camino = vec![v];
+            let mut visitados = vec![false; n];
+            visitados[v] = true;
+            if self.extender(&mut camino, &mut visitados, ciclo)?
+            {
+                return Some(Some(camino));
+            }
+        }
+        Some(None)
+    }
+
+    ///
+    /// POST: Identificadores del camino o ciclo por el metodo de Held-Karp, con su estado
+    /// 
+    fn held_karp(&mut self, ciclo: bool) -> Option<Option<Vec<usize>>>
+    {
+        let n = self.sucesores.len();
+        if n > LIMITE_HELD_KARP
+        {
+            return None;
+        }
+        let adyacencia: Vec<u32> = self.sucesores.iter()
+                            .map(|x| x.iter().fold(0, |acc, w| acc | (1 << w)))
+                            .collect();
+        let completo: u32 = (1 << n) - 1;
+        // finales[mascara]: vertices en los que puede terminar un camino que recorre exactamente la mascara
+        let mut finales: Vec<u32> = vec![0; 1 << n];
+        match ciclo {
+            true => { finales[1] = 1; },
+            false => {
+                for v in 0..n
+                {
+                    finales[1 << v] = 1 << v;
+                }
+            }
+        }
+        for mascara in 1..=completo
+        {
+            if self.consumir()
+            {
+                return None;
+            }
+            let mut pendientes = finales[mascara as usize];
+            while pendientes != 0
+            {
+                let v = pendientes.trailing_zeros() as usize;
+                pendientes &= pendientes - 1;
+                let mut siguientes = adyacencia[v] & !mascara;
+                while siguientes != 0
+                {
+                    let w = siguientes.trailing_zeros();
+                    siguientes &= siguientes - 1;
+                    finales[(mascara | (1 << w)) as usize] |= 1 << w;
+                }
+            }
+        }
+
+        // Reconstruccion hacia atras desde un vertice final valido
+        let validos = match ciclo {
+            true => (0..n).filter(|v| adyacencia[*v] & 1 != 0).fold(0u32, |acc, v| acc | (1 << v)),
+            false => completo
+        };
+        let candidatos = finales[completo as usize] & validos;
+        if candidatos == 0
+        {
+            return Some(None);
+        }
+        let mut v = candidatos.trailing_zeros() as usize;
+        let mut mascara = completo;
+        let mut camino = vec![v];
+        while mascara.count_ones() > 1
+        {
+            mascara ^= 1 << v;
+            let previos = finales[mascara as usize];
+            v = (0..n).find(|u| previos & (1 << u) != 0 && adyacencia[*u] & (1 << v) != 0).unwrap();
+            camino.push(v);
+        }
+        camino.reverse();
+        Some(Some(camino))
+    }
+}
+
+///
+/// Funcion auxiliar. Ciclos hamiltonianos de uno o dos vertices, que requieren un lazo o dos aristas distintas
+/// 
+fn ciclo_trivial<Graf, Vertice, Peso>(grafo: &Graf) -> Option<Vec<&Vertice>>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
+{
+    let vertices = grafo.get_vertices();
+    let v = vertices[0];
+    let w = *vertices.last().unwrap();
+    let ida: Vec<&Graf::Arista> = grafo.aristas_accesibles(v).into_iter()
+                        .filter(|x| x.other(v) == Some(w))
+                        .collect();
+    let existe = match vertices.len() {
+        1 => !ida.is_empty(),
+        _ => ida.iter().any(|x| grafo.aristas_accesibles(w).into_iter()
+                                    .any(|y| y.other(w) == Some(v) && !std::ptr::eq(*x, y)))
+    };
+    match existe {
+        true => Some(vertices.into_iter().take(2).collect()),
+        false => None
+    }
+}
+
+///
+/// Funcion auxiliar. Busqueda comun a caminos y ciclos
+/// 
+fn buscar<'a, Graf, Vertice, Peso>(grafo: &'a Graf, metodo: MetodoHamilton, presupuesto: &Presupuesto, ciclo: bool) -> ResultadoHamilton<'a, Vertice>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
+{
+    let n = grafo.size();
+    if n == 0
+    {
+        return ResultadoHamilton::Inexistente;
+    }
+    if ciclo && n <= 2
+    {
+        return match ciclo_trivial(grafo) {
+            Some(vertices) => ResultadoHamilton::Encontrado(vertices),
+            None => ResultadoHamilton::Inexistente
+        };
+    }
+
+    let (mut busqueda, vertices) = Busqueda::new(grafo, presupuesto);
+    let resultado = match metodo {
+        MetodoHamilton::Backtracking => busqueda.backtracking(ciclo),
+        MetodoHamilton::HeldKarp => busqueda.held_karp(ciclo)
+    };
+    match resultado {
+        Some(Some(camino)) => ResultadoHamilton::Encontrado(camino.into_iter().map(|id| vertices[id]).collect()),
+        Some(None) => ResultadoHamilton::Inexistente,
+        None => ResultadoHamilton::Desconocido
+    }
+}
+
+///
+/// PRE: Grafo o digrafo, metodo de busqueda y presupuesto
+/// POST: Camino que pasa exactamente una vez por cada vertice, si existe. Desconocido si se agota el presupuesto
+/// o si se usa Held-Karp con mas de [`LIMITE_HELD_KARP`] vertices
+/// NOTA: En un Digrafo se respeta el sentido de los arcos. Un grafo vacio no tiene camino hamiltoniano
+/// 
+pub fn camino_hamiltoniano<'a, Graf, Vertice, Peso>(grafo: &'a Graf, metodo: MetodoHamilton, presupuesto: &Presupuesto) -> ResultadoHamilton<'a, Vertice>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
+{
+    buscar(grafo, metodo, presupuesto, false)
+}
+
+///
+/// PRE: Grafo o digrafo, metodo de busqueda y presupuesto
+/// POST: Ciclo que pasa exactamente una vez por cada vertice, si existe. Desconocido si se agota el presupuesto
+/// o si se usa Held-Karp con mas de [`LIMITE_HELD_KARP`] vertices
+/// NOTA: En un Digrafo se respeta el sentido de los arcos. Con un vertice se necesita un lazo y con dos,
+/// dos aristas distintas entre ellos
+/// 
+pub fn ciclo_hamiltoniano<'a, Graf, Vertice, Peso>(grafo: &'a Graf, metodo: MetodoHamilton, presupuesto: &Presupuesto) -> ResultadoHamilton<'a, Vertice>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
+{
+    buscar(grafo, metodo, presupuesto, true)
+}
@@ -0,0 +1,153 @@
+use crate::grafo_rs::{Arista, Diarista, Grafo, Digrafo, GrafoT, NoPeso};
+use super::*;
+
+///
+/// Comprueba que la secuencia pase una vez por cada vertice y que vertices consecutivos sean adyacentes
+/// 
+fn comprobar<Graf>(grafo: &Graf, resultado: ResultadoHamilton<'_, i32>, ciclo: bool) -> bool
+where Graf: GrafoT<i32, NoPeso>
+{
+    let vertices = match resultado {
+        ResultadoHamilton::Encontrado(vertices) => vertices,
+        _ => { return false; }
+    };
+    let mut ordenados: Vec<i32> = vertices.iter().map(|v| **v).collect();
+    ordenados.sort();
+    let mut todos: Vec<i32> = grafo.get_vertices().into_iter().copied().collect();
+    todos.sort();
+    assert_eq!(ordenados, todos, "Debe pasar una vez por cada vertice");
+
+    let mut pares: Vec<(&i32, &i32)> = vertices.windows(2).map(|x| (x[0], x[1])).collect();
+    if ciclo
+    {
+        pares.push((vertices[vertices.len() - 1], vertices[0]));
+    }
+    for (v, w) in pares
+    {
+        assert!(grafo.aristas_accesibles(v).into_iter().any(|x| x.other(v) == Some(w)), "{} y {} no son adyacentes", v, w);
+    }
+    true
+}
+
+fn petersen() -> Grafo<i32, NoPeso>
+{
+    let mut aristas = vec![];
+    for i in 0..5
+    {
+        aristas.push(Arista::arista_sin_peso(i, (i + 1) % 5));
+        aristas.push(Arista::arista_sin_peso(i, i + 5));
+        aristas.push(Arista::arista_sin_peso(i + 5, (i + 2) % 5 + 5));
+    }
+    Grafo::from_aristas(aristas)
+}
+
+#[test]
+fn test_hamilton_grafo()
+{
+    let g = petersen();
+    for metodo in [MetodoHamilton::Backtracking, MetodoHamilton::HeldKarp]
+    {
+        assert!(comprobar(&g, camino_hamiltoniano(&g, metodo, &Presupuesto::ilimitado()), false));
+    }
+    // El grafo de Petersen no es hamiltoniano
+    assert!(matches!(ciclo_hamiltoniano(&g, MetodoHamilton::Backtracking, &Presupuesto::ilimitado()), ResultadoHamilton::Inexistente));
+    assert!(matches!(ciclo_hamiltoniano(&g, MetodoHamilton::HeldKarp, &Presupuesto::ilimitado()), ResultadoHamilton::Inexistente));
+
+    // Al quitar un vertice si lo es
+    let mut h = g.clone();
+    h.remove_vertice(&0);
+    assert!(comprobar(&h, ciclo_hamiltoniano(&h, MetodoHamilton::Backtracking, &Presupuesto::ilimitado()), true));
+    assert!(comprobar(&h, ciclo_hamiltoniano(&h, MetodoHamilton::HeldKarp, &Presupuesto::ilimitado()), true));
+
+    // Una estrella con tres hojas no tiene camino hamiltoniano
+    let estrella: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(0, 1),
+                                                                Arista::arista_sin_peso(0, 2),
+                                                                Arista::arista_sin_peso(0, 3)].to_vec());
+    assert!(matches!(camino_hamiltoniano(&estrella, MetodoHamilton::Backtracking, &Presupuesto::ilimitado()), ResultadoHamilton::Inexistente));
+    assert!(matches!(camino_hamiltoniano(&estrella, MetodoHamilton::HeldKarp, &Presupuesto::ilimitado()), ResultadoHamilton::Inexistente));
+}
+
+#[test]
+fn test_hamilton_digrafo()
+{
+    let digrafo: Digrafo<i32, NoPeso> = Digrafo::from_aristas([Diarista::arista_sin_peso(1, 2),
+                                                                Diarista::arista_sin_peso(2, 3),
+                                                                Diarista::arista_sin_peso(3, 4),
+                                                                Diarista::arista_sin_peso(4, 2),
+                                                                Diarista::arista_sin_peso(1, 3)].to_vec());
+    for metodo in [MetodoHamilton::Backtracking, MetodoHamilton::HeldKarp]
+    {
+        assert!(comprobar(&digrafo, camino_hamiltoniano(&digrafo, metodo, &Presupuesto::ilimitado()), false));
+    }
+    // Ningun arco entra en 1
+    assert!(matches!(ciclo_hamiltoniano(&digrafo, MetodoHamilton::Backtracking, &Presupuesto::ilimitado()), ResultadoHamilton::Inexistente));
+    assert!(matches!(ciclo_hamiltoniano(&digrafo, MetodoHamilton::HeldKarp, &Presupuesto::ilimitado()), ResultadoHamilton::Inexistente));
+
+    let mut ciclo = digrafo.clone();
+    ciclo.add_aristas(vec![Diarista::arista_sin_peso(4, 1)]);
+    assert!(comprobar(&ciclo, ciclo_hamiltoniano(&ciclo, MetodoHamilton::Backtracking, &Presupuesto::ilimitado()), true));
+    assert!(comprobar(&ciclo, ciclo_hamiltoniano(&ciclo, MetodoHamilton::HeldKarp, &Presupuesto::ilimitado()), true));
+}
+
+#[test]
+fn test_hamilton_trivial()
+{
+    let vacio: Grafo<i32, NoPeso> = Grafo::new();
+    assert!(matches!(camino_hamiltoniano(&vacio, MetodoHamilton::Backtracking, &Presupuesto::ilimitado()), ResultadoHamilton::Inexistente));
+
+    let aislado: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::vertice(1)].to_vec());
+    assert!(comprobar(&aislado, camino_hamiltoniano(&aislado, MetodoHamilton::HeldKarp, &Presupuesto::ilimitado()), false));
+    assert!(matches!(ciclo_hamiltoniano(&aislado, MetodoHamilton::Backtracking, &Presupuesto::ilimitado()), ResultadoHamilton::Inexistente));
+
+    let lazo: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(1, 1)].to_vec());
+    assert!(comprobar(&lazo, ciclo_hamiltoniano(&lazo, MetodoHamilton::Backtracking, &Presupuesto::ilimitado()), true));
+
+    // Una unica arista no forma un ciclo, pero dos arcos opuestos si
+    let arista: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(1, 2)].to_vec());
+    assert!(matches!(ciclo_hamiltoniano(&arista, MetodoHamilton::HeldKarp, &Presupuesto::ilimitado()), ResultadoHamilton::Inexistente));
+    let arcos: Digrafo<i32, NoPeso> = Digrafo::from_aristas([Diarista::arista_sin_peso(1, 2),
+                                                             Diarista::arista_sin_peso(2, 1)].to_vec());
+    assert!(comprobar(&arcos, ciclo_hamiltoniano(&arcos, MetodoHamilton::HeldKarp, &Presupuesto::ilimitado()), true));
+}
+
+#[test]
+fn test_hamilton_presupuesto()
+{
+    let g = petersen();
+    let presupuesto = Presupuesto::new(Some(1), None);
+    assert_eq!(presupuesto.get_nodos(), Some(1));
+    assert!(matches!(ciclo_hamiltoniano(&g, MetodoHamilton::Backtracking, &presupuesto), ResultadoHamilton::Desconocido));
+    assert!(matches!(ciclo_hamiltoniano(&g, MetodoHamilton::HeldKarp, &presupuesto), ResultadoHamilton::Desconocido));
+
+    // Held-Karp no admite grafos grandes
+    let camino: Grafo<i32, NoPeso> = Grafo::from_aristas((0..LIMITE_HELD_KARP as i32).map(|i| Arista::arista_sin_peso(i, i + 1)).collect());
+    assert!(matches!(camino_hamiltoniano(&camino, MetodoHamilton::HeldKarp, &Presupuesto::ilimitado()), ResultadoHamilton::Desconocido));
+    assert!(comprobar(&camino, camino_hamiltoniano(&camino, MetodoHamilton::Backtracking, &Presupuesto::ilimitado()), false));
+}
+
+#[test]
+fn test_hamilton_metodos_coinciden()
+{
+    // Todos los subgrafos de K4 mas un vertice colgante
+    let pares = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3), (3, 4)];
+    for mascara in 0..(1 << pares.len())
+    {
+        let aristas: Vec<Arista<i32, NoPeso>> = pares.iter().enumerate()
+                        .filter(|(i, _)| mascara & (1 << i) != 0)
+                        .map(|(_, (v, w))| Arista::arista_sin_peso(*v, *w))
+                        .collect();
+        let mut g = Grafo::from_aristas(aristas);
+        let faltan: Vec<i32> = (0..5).filter(|v| !g.get_vertices().contains(&v)).collect();
+        g.add_vertices(faltan);
+        for ciclo in [false, true]
+        {
+            let buscar = |metodo| match ciclo {
+                true => ciclo_hamiltoniano(&g, metodo, &Presupuesto::ilimitado()),
+                false => camino_hamiltoniano(&g, metodo, &Presupuesto::ilimitado())
+            };
+            let a = comprobar(&g, buscar(MetodoHamilton::Backtracking), ciclo);
+            let b = comprobar(&g, buscar(MetodoHamilton::HeldKarp), ciclo);
+            assert_eq!(a, b, "Aristas {:b}", mascara);
+        }
+    }
+}
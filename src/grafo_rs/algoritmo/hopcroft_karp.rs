@@ -0,0 +1,166 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::grafo_rs::{Arista, AristaT, Bipartido, Emparejamiento, GrafoT, PesoT, VerticeT};
+
+#[cfg(test)]
+mod tests;
+
+///
+/// Estructura auxiliar con la lista de adyacencia de la primera particion y las parejas actuales
+/// 
+struct HopcroftKarp<'a, Vertice, Peso>
+where Vertice: VerticeT, Peso: PesoT
+{
+    adyacencia: Vec<Vec<(usize, &'a Arista<Vertice, Peso>)>>,
+    pareja_x: Vec<Option<(usize, &'a Arista<Vertice, Peso>)>>,
+    pareja_y: Vec<Option<usize>>,
+    niveles: Vec<Option<usize>>,
+    siguiente: Vec<usize>
+}
+
+impl<'a, Vertice, Peso> HopcroftKarp<'a, Vertice, Peso>
+where Vertice: VerticeT, Peso: PesoT
+{
+    fn new(bipartido: &'a Bipartido<Vertice, Peso>) -> Self
+    {
+        let ids_x: HashMap<&Vertice, usize> = bipartido.get_vertices_x().into_iter().enumerate()
+                                                .map(|(i, v)| (v, i))
+                                                .collect();
+        let ids_y: HashMap<&Vertice, usize> = bipartido.get_vertices_y().into_iter().enumerate()
+                                                .map(|(i, v)| (v, i))
+                                                .collect();
+        let mut adyacencia = vec![vec![]; ids_x.len()];
+        for arista in bipartido.get_aristas()
+        {
+            let (x, y) = arista.get_vertices().unwrap();
+            adyacencia[ids_x[x]].push((ids_y[y], arista));
+        }
+        Self {
+            adyacencia,
+            pareja_x: vec![None; ids_x.len()],
+            pareja_y: vec![None; ids_y.len()],
+            niveles: vec![None; ids_x.len()],
+            siguiente: vec![0; ids_x.len()]
+        }
+    }
+
+    ///
+    /// POST: Niveles de la busqueda en anchura desde los vertices libres de X. true si hay algun camino de aumento
+    /// 
+    fn niveles(&mut self) -> bool
+    {
+        let mut cola = VecDeque::new();
+        for x in 0..self.adyacencia.len()
+        {
+            self.niveles[x] = match self.pareja_x[x] {
+                Some(_) => None,
+                None => {
+                    cola.push_back(x);
+                    Some(0)
+                }
+            };
+        }
+        let mut encontrado = false;
+        while let Some(x) = cola.pop_front()
+        {
+            let nivel = self.niveles[x].unwrap();
+            for (y, _) in self.adyacencia[x].iter()
+            {
+                match self.pareja_y[*y] {
+                    None => { encontrado = true; },
+                    Some(x2) if self.niveles[x2].is_none() => {
+                        self.niveles[x2] = Some(nivel + 1);
+                        cola.push_back(x2);
+                    },
+                    _ => {}
+                }
+            }
+        }
+        encontrado
+    }
+
+    ///
+    /// POST: Busca en profundidad, por niveles crecientes, un camino de aumento desde la raiz y lo aplica.
+    /// true si lo encuentra
+    /// NOTA: Iterativo para no depender de la longitud de los caminos
+    /// 
+    fn aumentar(&mut self, raiz: usize) -> bool
+    {
+        let mut pila = vec![raiz];
+        while let Some(x) = pila.last().copied()
+        {
+            let (y, _) = match self.adyacencia[x].get(self.siguiente[x]) {
+                Some(arista) => *arista,
+                None => {
+                    // Sin salida: el vertice no vuelve a visitarse en esta fase
+                    self.niveles[x] = None;
+                    pila.pop();
+                    continue;
+                }
+            };
+            match self.pareja_y[y] {
+                None => {
+                    for x in pila
+                    {
+                        let (y, arista) = self.adyacencia[x][self.siguiente[x]];
+                        self.pareja_x[x] = Some((y, arista));
+                        self.pareja_y[y] = Some(x);
+                    }
+                    return true;
+                },
+                Some(x2) if self.niveles[x2].is_some() && self.niveles[x2] == self.niveles[x].map(|n| n + 1) => {
+                    pila.push(x2);
+                },
+                _ => { self.siguiente[x] += 1; }
+            }
+        }
+        false
+    }
+}
+
+///
+/// PRE: Grafo bipartido
+/// POST: Emparejamiento de cardinal maximo formado por aristas del bipartido
+/// NOTA: Algoritmo de Hopcroft-Karp. O(m raiz(n))
+/// 
+pub fn hopcroft_karp<Vertice, Peso>(bipartido: &Bipartido<Vertice, Peso>) -> Emparejamiento<'_, Arista<Vertice, Peso>, Vertice, Peso>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let mut algoritmo = HopcroftKarp::new(bipartido);
+    while algoritmo.niveles()
+    {
+        algoritmo.siguiente.iter_mut().for_each(|x| *x = 0);
+        for x in 0..algoritmo.adyacencia.len()
+        {
+            if algoritmo.pareja_x[x].is_none()
+            {
+                algoritmo.aumentar(x);
+            }
+        }
+    }
+    let aristas = algoritmo.pareja_x.into_iter().flatten().map(|(_, arista)| arista).collect();
+    Emparejamiento::from_disjuntas(aristas)
+}
+
+///
+/// PRE: Emparejamiento y grafo bipartido que contiene sus aristas
+/// POST: true si no existe un emparejamiento del bipartido con mas aristas
+/// 
+pub fn es_maximo<Vertice, Peso>(emparejamiento: &Emparejamiento<'_, Arista<Vertice, Peso>, Vertice, Peso>, bipartido: &Bipartido<Vertice, Peso>) -> bool
+where Vertice: VerticeT, Peso: PesoT
+{
+    emparejamiento.size() == hopcroft_karp(bipartido).size()
+}
+
+///
+/// PRE: Emparejamiento y grafo que contiene sus aristas
+/// POST: true si el emparejamiento satura todos los vertices del grafo
+/// 
+pub fn es_perfecto<Graf, Vertice, Peso>(emparejamiento: &Emparejamiento<'_, Graf::Arista, Vertice, Peso>, grafo: &Graf) -> bool
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
+{
+    let saturados: HashSet<&Vertice> = emparejamiento.get_aristas().iter()
+                        .flat_map(|x| { let (u, v) = x.get_vertices().unwrap(); [u, v] })
+                        .collect();
+    grafo.get_vertices().into_iter().all(|v| saturados.contains(v))
+}
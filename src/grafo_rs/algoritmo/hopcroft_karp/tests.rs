@@ -0,0 +1,107 @@
+use crate::grafo_rs::{Arista, Bipartido, Emparejamiento, Grafo, NoPeso};
+use super::*;
+
+///
+/// Corona: grafo bipartito completo sin un emparejamiento perfecto
+/// 
+fn corona(n: i32) -> Grafo<i32, NoPeso>
+{
+    let mut aristas = vec![];
+    for i in 0..n
+    {
+        for j in (0..n).filter(|j| *j != i)
+        {
+            aristas.push(Arista::arista_sin_peso(i, n + j));
+        }
+    }
+    Grafo::from_aristas(aristas)
+}
+
+#[test]
+fn test_hopcroft_karp()
+{
+    // Trabajadores 1..=4 y puestos 10..=13
+    let g: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(1, 10),
+                                                       Arista::arista_sin_peso(1, 11),
+                                                       Arista::arista_sin_peso(2, 10),
+                                                       Arista::arista_sin_peso(3, 11),
+                                                       Arista::arista_sin_peso(3, 12),
+                                                       Arista::arista_sin_peso(4, 12),
+                                                       Arista::arista_sin_peso(4, 13)].to_vec());
+    let bipartido = Bipartido::from_grafo(&g).expect("Grafo bipartido");
+    let emparejamiento = hopcroft_karp(&bipartido);
+    assert_eq!(emparejamiento.size(), 4);
+    assert_eq!(emparejamiento.recorrer(&2), Some(&10));
+    assert_eq!(emparejamiento.recorrer(&1), Some(&11));
+    assert!(es_perfecto(&emparejamiento, &bipartido));
+    assert!(es_maximo(&emparejamiento, &bipartido));
+}
+
+#[test]
+fn test_hopcroft_karp_hall()
+{
+    // Tres vertices que solo conocen a dos: no se pueden saturar todos
+    let g: Grafo<char, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso('1', 'a'),
+                                                        Arista::arista_sin_peso('2', 'a'),
+                                                        Arista::arista_sin_peso('2', 'b'),
+                                                        Arista::arista_sin_peso('3', 'b'),
+                                                        Arista::vertice('c')].to_vec());
+    let bipartido = Bipartido::from_grafo(&g).unwrap();
+    let emparejamiento = hopcroft_karp(&bipartido);
+    assert_eq!(emparejamiento.size(), 2);
+    assert!(es_maximo(&emparejamiento, &bipartido));
+    assert!(!es_perfecto(&emparejamiento, &bipartido));
+
+    let vacio: Bipartido<i32, NoPeso> = Bipartido::from_grafo(&Grafo::new()).unwrap();
+    assert_eq!(hopcroft_karp(&vacio).size(), 0);
+}
+
+#[test]
+fn test_hopcroft_karp_corona()
+{
+    let g = corona(30);
+    let bipartido = Bipartido::from_grafo(&g).unwrap();
+    let emparejamiento = hopcroft_karp(&bipartido);
+    assert_eq!(emparejamiento.size(), 30);
+    assert!(es_perfecto(&emparejamiento, &g));
+    assert!(emparejamiento.get_aristas().iter().all(|x| g.get_aristas().contains(x)));
+}
+
+#[test]
+fn test_es_maximo()
+{
+    // Camino 1-2-3-4: la arista central sola es maximal pero no maxima
+    let g: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(1, 2),
+                                                       Arista::arista_sin_peso(2, 3),
+                                                       Arista::arista_sin_peso(3, 4)].to_vec());
+    let bipartido = Bipartido::from_grafo(&g).unwrap();
+    let central = bipartido.get_aristas().iter()
+                    .find(|x| x.arista_contiene_vertice(&2) && x.arista_contiene_vertice(&3))
+                    .unwrap();
+    let maximal = Emparejamiento::new(vec![central]).unwrap();
+    assert!(!es_maximo(&maximal, &bipartido));
+    assert!(!es_perfecto(&maximal, &bipartido));
+
+    let maximo = hopcroft_karp(&bipartido);
+    assert_eq!(maximo.size(), 2);
+    assert!(es_maximo(&maximo, &bipartido));
+    assert!(es_perfecto(&maximo, &bipartido));
+}
+
+#[test]
+fn test_hopcroft_karp_grande()
+{
+    // Cada x se une a su y y a la siguiente: hay un emparejamiento perfecto de n aristas
+    let n = 5000;
+    let mut aristas = vec![];
+    for i in 0..n
+    {
+        aristas.push(Arista::arista_sin_peso(i, n + i));
+        aristas.push(Arista::arista_sin_peso(i, n + (i + 1) % n));
+    }
+    let g: Grafo<i32, NoPeso> = Grafo::from_aristas(aristas);
+    let bipartido = Bipartido::from_grafo(&g).unwrap();
+    let emparejamiento = hopcroft_karp(&bipartido);
+    assert_eq!(emparejamiento.size(), n as usize);
+    assert!(es_perfecto(&emparejamiento, &g));
+}
@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+
+use crate::grafo_rs::{Arista, AristaT, Bipartido, Emparejamiento, GrafoT, PesoT, VerticeT};
+
+#[cfg(test)]
+mod tests;
+
+///
+/// Criterio de optimizacion de la asignacion
+/// 
+pub enum ObjetivoAsignacion
+{
+    /// Mayor numero de aristas y, entre esos emparejamientos, menor suma de pesos, interpretados como costes
+    CosteMinimo,
+    /// Mayor suma de pesos, sin importar el numero de aristas
+    PesoMaximo
+}
+
+///
+/// Asignacion: emparejamiento elegido y suma de los pesos de sus aristas
+/// 
+pub type Asignacion<'a, Vertice, Peso> = (Emparejamiento<'a, Arista<Vertice, Peso>, Vertice, Peso>, Peso);
+
+///
+/// Funcion auxiliar. Asignacion de coste minimo de cada fila a una columna distinta, con al menos tantas columnas
+/// como filas. Las parejas sin coste se evitan siempre que sea posible y se devuelven como None
+/// NOTA: Metodo hungaro con potenciales. O(n² m). Las parejas sin coste se sustituyen por un coste mayor que
+/// la suma de todos los demas, por lo que se asigna el maximo numero posible de parejas con coste
+/// 
+fn asignacion_minima(costes: &[Vec<Option<isize>>]) -> Vec<Option<usize>>
+{
+    let n = costes.len();
+    let m = costes.first().map_or(0, |x| x.len());
+    let prohibido = costes.iter().flatten().flatten().map(|x| x.abs()).sum::<isize>() + 1;
+    let coste = |i: usize, j: usize| costes[i][j].unwrap_or(prohibido);
+
+    // Indices desde 1: la fila y la columna 0 son ficticias
+    let mut potencial_filas = vec![0; n + 1];
+    let mut potencial_columnas = vec![0; m + 1];
+    let mut fila_de: Vec<usize> = vec![0; m + 1];
+    let mut previa: Vec<usize> = vec![0; m + 1];
+    for i in 1..=n
+    {
+        fila_de[0] = i;
+        let mut columna = 0;
+        let mut minimos = vec![isize::MAX; m + 1];
+        let mut usadas = vec![false; m + 1];
+        // Camino alternado de coste reducido minimo hasta una columna libre
+        loop
+        {
+            usadas[columna] = true;
+            let fila = fila_de[columna];
+            let mut delta = isize::MAX;
+            let mut siguiente = 0;
+            for j in 1..=m
+            {
+                if usadas[j]
+                {
+                    continue;
+                }
+                let reducido = coste(fila - 1, j - 1) - potencial_filas[fila] - potencial_columnas[j];
+                if reducido < minimos[j]
+                {
+                    minimos[j] = reducido;
+                    previa[j] = columna;
+                }
+                if minimos[j] < delta
+                {
+                    delta = minimos[j];
+                    siguiente = j;
+                }
+            }
+            for j in 0..=m
+            {
+                if usadas[j]
+                {
+                    potencial_filas[fila_de[j]] += delta;
+                    potencial_columnas[j] -= delta;
+                }
+                else
+                {
+                    minimos[j] -= delta;
+                }
+            }
+            columna = siguiente;
+            if fila_de[columna] == 0
+            {
+                break;
+            }
+        }
+        // Se invierte el camino
+        while columna != 0
+        {
+            let anterior = previa[columna];
+            fila_de[columna] = fila_de[anterior];
+            columna = anterior;
+        }
+    }
+
+    let mut asignacion = vec![None; n];
+    for j in 1..=m
+    {
+        if fila_de[j] != 0 && costes[fila_de[j] - 1][j - 1].is_some()
+        {
+            asignacion[fila_de[j] - 1] = Some(j - 1);
+        }
+    }
+    asignacion
+}
+
+///
+/// PRE: Grafo bipartido con pesos
+/// POST: Emparejamiento optimo segun el objetivo, junto a la suma de sus pesos. Con CosteMinimo tiene el mayor
+/// numero posible de aristas y, entre los de ese tamaño, el menor coste. Con PesoMaximo tiene el mayor peso total
+/// y no usa aristas de peso no positivo. Las particiones pueden tener distinto tamaño.
+/// None si alguna arista no tiene peso
+/// NOTA: Metodo hungaro (Kuhn-Munkres) sobre la particion menor. O(n² m). Con PesoMaximo cada fila tiene ademas
+/// una columna ficticia de coste 0 para quedar libre. Los pesos se comparan con `PesoT::to_isize`. Entre aristas
+/// paralelas se elige la mejor para el objetivo
+/// 
+pub fn hungaro<Vertice, Peso>(bipartido: &Bipartido<Vertice, Peso>, objetivo: ObjetivoAsignacion) -> Option<Asignacion<'_, Vertice, Peso>>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let (vertices_x, vertices_y) = (bipartido.get_vertices_x(), bipartido.get_vertices_y());
+    // Las filas son la particion menor
+    let traspuesto = vertices_x.len() > vertices_y.len();
+    let (filas, columnas) = match traspuesto {
+        false => (vertices_x, vertices_y),
+        true => (vertices_y, vertices_x)
+    };
+    let ids_filas: HashMap<&Vertice, usize> = filas.iter().enumerate()
+                                                .map(|(i, v)| (*v, i))
+                                                .collect();
+    let ids_columnas: HashMap<&Vertice, usize> = columnas.iter().enumerate()
+                                                    .map(|(i, v)| (*v, i))
+                                                    .collect();
+    let signo = match objetivo {
+        ObjetivoAsignacion::CosteMinimo => 1,
+        ObjetivoAsignacion::PesoMaximo => -1
+    };
+
+    let mut mejores: Vec<Vec<Option<&Arista<Vertice, Peso>>>> = vec![vec![None; columnas.len()]; filas.len()];
+    let mut costes: Vec<Vec<Option<isize>>> = vec![vec![None; columnas.len()]; filas.len()];
+    for arista in bipartido.get_aristas()
+    {
+        let coste = signo * arista.get_peso()?.to_isize();
+        if signo < 0 && coste >= 0
+        {
+            continue;
+        }
+        let (x, y) = arista.get_vertices().unwrap();
+        let (i, j) = match traspuesto {
+            false => (ids_filas[x], ids_columnas[y]),
+            true => (ids_filas[y], ids_columnas[x])
+        };
+        if costes[i][j].is_none_or(|actual| coste < actual)
+        {
+            costes[i][j] = Some(coste);
+            mejores[i][j] = Some(arista);
+        }
+    }
+
+    if signo < 0
+    {
+        // Columnas ficticias: la fila i puede quedar libre con coste 0
+        let n = filas.len();
+        for (i, fila) in costes.iter_mut().enumerate()
+        {
+            fila.extend((0..n).map(|j| if i == j { Some(0) } else { None }));
+        }
+    }
+
+    let aristas: Vec<&Arista<Vertice, Peso>> = asignacion_minima(&costes).into_iter().enumerate()
+                                                .filter_map(|(i, j)| *mejores[i].get(j?)?)
+                                                .collect();
+    let total = aristas.iter().fold(Peso::elemento_neutro(), |acc, x| acc.suma(x.get_peso().unwrap()));
+    Some((Emparejamiento::from_disjuntas(aristas), total))
+}
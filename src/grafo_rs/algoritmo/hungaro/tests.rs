@@ -0,0 +1,139 @@
+use crate::grafo_rs::{Arista, Bipartido, Grafo, NoPeso};
+use super::*;
+
+///
+/// Bipartido completo entre las filas 0.. y las columnas 100.. con la matriz de pesos dada
+/// 
+fn matriz(pesos: &[Vec<isize>]) -> Bipartido<i32, isize>
+{
+    let mut aristas = vec![];
+    for (i, fila) in pesos.iter().enumerate()
+    {
+        for (j, peso) in fila.iter().enumerate()
+        {
+            aristas.push(Arista::arista(i as i32, 100 + j as i32, Some(*peso)));
+        }
+    }
+    Bipartido::from_grafo(&Grafo::from_aristas(aristas)).unwrap()
+}
+
+///
+/// Menor coste de asignar cada fila a una columna distinta probando todas las opciones. Si se permiten filas
+/// libres, estas no suman coste
+/// 
+fn fuerza_bruta(pesos: &[Vec<isize>], fila: usize, usadas: &mut Vec<bool>, libres: bool) -> isize
+{
+    if fila == pesos.len()
+    {
+        return 0;
+    }
+    let mut mejor = match libres {
+        true => fuerza_bruta(pesos, fila + 1, usadas, libres),
+        false => isize::MAX
+    };
+    for j in 0..usadas.len()
+    {
+        if !usadas[j]
+        {
+            usadas[j] = true;
+            mejor = mejor.min(pesos[fila][j] + fuerza_bruta(pesos, fila + 1, usadas, libres));
+            usadas[j] = false;
+        }
+    }
+    mejor
+}
+
+#[test]
+fn test_hungaro()
+{
+    let pesos = vec![vec![4, 1, 3], vec![2, 0, 5], vec![3, 2, 2]];
+    let bipartido = matriz(&pesos);
+    let (emparejamiento, coste) = hungaro(&bipartido, ObjetivoAsignacion::CosteMinimo).unwrap();
+    assert_eq!(coste, 5);
+    assert_eq!(emparejamiento.size(), 3);
+    assert_eq!(emparejamiento.recorrer(&0), Some(&101));
+    assert_eq!(emparejamiento.recorrer(&1), Some(&100));
+    assert_eq!(emparejamiento.recorrer(&2), Some(&102));
+
+    let (emparejamiento, peso) = hungaro(&bipartido, ObjetivoAsignacion::PesoMaximo).unwrap();
+    assert_eq!(peso, 11);
+    assert_eq!(emparejamiento.size(), 3);
+}
+
+#[test]
+fn test_hungaro_fuerza_bruta()
+{
+    for semilla in 0..10
+    {
+        let pesos: Vec<Vec<isize>> = (0..5)
+                        .map(|i| (0..5).map(|j| (i * 7 + j * 13 + semilla * 31) % 17 - 4).collect())
+                        .collect();
+        let (_, coste) = hungaro(&matriz(&pesos), ObjetivoAsignacion::CosteMinimo).unwrap();
+        assert_eq!(coste, fuerza_bruta(&pesos, 0, &mut vec![false; 5], false));
+
+        // Con peso maximo las filas pueden quedar libres en lugar de usar pesos negativos
+        let opuestos: Vec<Vec<isize>> = pesos.iter().map(|x| x.iter().map(|p| -p).collect()).collect();
+        let bipartido = matriz(&pesos);
+        let (emparejamiento, peso) = hungaro(&bipartido, ObjetivoAsignacion::PesoMaximo).unwrap();
+        assert_eq!(peso, -fuerza_bruta(&opuestos, 0, &mut vec![false; 5], true));
+        assert!(emparejamiento.get_aristas().iter().all(|x| *x.get_peso().unwrap() > 0));
+    }
+}
+
+#[test]
+fn test_hungaro_desequilibrado()
+{
+    // Mas columnas que filas: cada fila recibe una columna
+    let pesos = vec![vec![9, 2, 7, 8], vec![6, 4, 3, 7]];
+    let bipartido = matriz(&pesos);
+    let (emparejamiento, coste) = hungaro(&bipartido, ObjetivoAsignacion::CosteMinimo).unwrap();
+    assert_eq!((emparejamiento.size(), coste), (2, 5));
+
+    // Mas filas que columnas: se asigna cada columna
+    let traspuesta: Vec<Vec<isize>> = (0..4).map(|j| pesos.iter().map(|x| x[j]).collect()).collect();
+    let bipartido = matriz(&traspuesta);
+    let (emparejamiento, peso) = hungaro(&bipartido, ObjetivoAsignacion::PesoMaximo).unwrap();
+    assert_eq!((emparejamiento.size(), peso), (2, 16));
+}
+
+#[test]
+fn test_hungaro_incompleto()
+{
+    // Se prefiere emparejar a los dos aunque una sola arista sea mas barata
+    let g: Grafo<i32, isize> = Grafo::from_aristas([Arista::arista(1, 10, Some(1)),
+                                                      Arista::arista(1, 11, Some(20)),
+                                                      Arista::arista(2, 10, Some(30)),
+                                                      Arista::arista(1, 10, Some(5)),
+                                                      Arista::vertice(3)].to_vec());
+    let bipartido = Bipartido::from_grafo(&g).unwrap();
+    let (emparejamiento, coste) = hungaro(&bipartido, ObjetivoAsignacion::CosteMinimo).unwrap();
+    assert_eq!((emparejamiento.size(), coste), (2, 50));
+
+    let (emparejamiento, peso) = hungaro(&bipartido, ObjetivoAsignacion::PesoMaximo).unwrap();
+    assert_eq!((emparejamiento.size(), peso), (2, 50));
+    assert!(*emparejamiento.buscar_arista(&1).unwrap() == Arista::arista(1, 11, Some(20)));
+
+    // Con peso maximo no se sacrifica peso por emparejar mas vertices
+    let g: Grafo<i32, isize> = Grafo::from_aristas([Arista::arista(1, 10, Some(10)),
+                                                      Arista::arista(1, 11, Some(1)),
+                                                      Arista::arista(2, 10, Some(1))].to_vec());
+    let bipartido = Bipartido::from_grafo(&g).unwrap();
+    let (emparejamiento, peso) = hungaro(&bipartido, ObjetivoAsignacion::PesoMaximo).unwrap();
+    assert_eq!((emparejamiento.size(), peso), (1, 10));
+    assert_eq!(emparejamiento.recorrer(&1), Some(&10));
+    let (emparejamiento, coste) = hungaro(&bipartido, ObjetivoAsignacion::CosteMinimo).unwrap();
+    assert_eq!((emparejamiento.size(), coste), (2, 2));
+
+    // Entre aristas paralelas se usa la mejor para el objetivo
+    let paralelas: Grafo<i32, isize> = Grafo::from_aristas([Arista::arista(1, 10, Some(1)),
+                                                              Arista::arista(1, 10, Some(5))].to_vec());
+    let bipartido = Bipartido::from_grafo(&paralelas).unwrap();
+    assert_eq!(hungaro(&bipartido, ObjetivoAsignacion::CosteMinimo).unwrap().1, 1);
+    assert_eq!(hungaro(&bipartido, ObjetivoAsignacion::PesoMaximo).unwrap().1, 5);
+
+    let sin_peso: Grafo<i32, isize> = Grafo::from_aristas([Arista::arista(1, 2, None)].to_vec());
+    assert!(hungaro(&Bipartido::from_grafo(&sin_peso).unwrap(), ObjetivoAsignacion::CosteMinimo).is_none());
+
+    let vacio: Bipartido<i32, NoPeso> = Bipartido::from_grafo(&Grafo::new()).unwrap();
+    assert_eq!(hungaro(&vacio, ObjetivoAsignacion::CosteMinimo).unwrap().0.size(), 0);
+}
@@ -0,0 +1,106 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::grafo_rs::{Arista, AristaT, Bipartido, Emparejamiento, GrafoT, PesoT, VerticeT};
+use crate::grafo_rs::algoritmo::hopcroft_karp;
+
+#[cfg(test)]
+mod tests;
+
+///
+/// Funcion auxiliar. Vertices alcanzables desde los vertices libres de la primera particion por caminos
+/// alternados: de X a Y por aristas del bipartido y de Y a X por aristas del emparejamiento.
+/// None si se alcanza un vertice libre de Y, es decir, si el emparejamiento no es maximo
+/// 
+fn alcanzables<'a, Vertice, Peso>(emparejamiento: &Emparejamiento<'_, Arista<Vertice, Peso>, Vertice, Peso>, bipartido: &'a Bipartido<Vertice, Peso>)
+    -> Option<HashSet<&'a Vertice>>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let vertices_x = bipartido.get_vertices_x();
+    let mut vecinos: HashMap<&Vertice, Vec<&Vertice>> = HashMap::new();
+    for arista in bipartido.get_aristas()
+    {
+        let (x, y) = arista.get_vertices().unwrap();
+        vecinos.entry(x).or_default().push(y);
+    }
+    let mut pareja: HashMap<&Vertice, &Vertice> = HashMap::new();
+    for arista in emparejamiento.get_aristas()
+    {
+        let (u, v) = arista.get_vertices().unwrap();
+        pareja.insert(u, v);
+        pareja.insert(v, u);
+    }
+
+    // Los vertices del emparejamiento se traducen a los del bipartido para devolver sus referencias
+    let en_x: HashMap<&Vertice, &'a Vertice> = vertices_x.iter().map(|x| (*x, *x)).collect();
+    let mut visitados: HashSet<&Vertice> = vertices_x.iter()
+                                            .filter(|x| !pareja.contains_key(*x))
+                                            .copied()
+                                            .collect();
+    let mut cola: VecDeque<&Vertice> = visitados.iter().copied().collect();
+    while let Some(x) = cola.pop_front()
+    {
+        for y in vecinos.get(x).into_iter().flatten()
+        {
+            if !visitados.insert(*y)
+            {
+                continue;
+            }
+            let x2 = *en_x.get(pareja.get(y)?)?;
+            if visitados.insert(x2)
+            {
+                cola.push_back(x2);
+            }
+        }
+    }
+    Some(visitados)
+}
+
+///
+/// PRE: Emparejamiento maximo formado por aristas del bipartido
+/// POST: Cubrimiento por vertices minimo: toda arista del bipartido tiene algun extremo en el, y su tamaño es
+/// el del emparejamiento. None si el emparejamiento no es maximo
+/// NOTA: Teorema de König. Se toman los vertices de X no alcanzables y los de Y alcanzables desde los vertices
+/// libres de X por caminos alternados
+/// 
+pub fn cubrimiento_minimo<'a, Vertice, Peso>(emparejamiento: &Emparejamiento<'_, Arista<Vertice, Peso>, Vertice, Peso>, bipartido: &'a Bipartido<Vertice, Peso>)
+    -> Option<Vec<&'a Vertice>>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let alcanzados = alcanzables(emparejamiento, bipartido)?;
+    Some(bipartido.get_vertices_x().into_iter().filter(|x| !alcanzados.contains(x))
+            .chain(bipartido.get_vertices_y().into_iter().filter(|y| alcanzados.contains(y)))
+            .collect())
+}
+
+///
+/// PRE: Emparejamiento maximo formado por aristas del bipartido
+/// POST: Conjunto independiente maximo: complementario del cubrimiento minimo, sin aristas entre sus vertices.
+/// None si el emparejamiento no es maximo
+/// 
+pub fn conjunto_independiente_maximo<'a, Vertice, Peso>(emparejamiento: &Emparejamiento<'_, Arista<Vertice, Peso>, Vertice, Peso>, bipartido: &'a Bipartido<Vertice, Peso>)
+    -> Option<Vec<&'a Vertice>>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let alcanzados = alcanzables(emparejamiento, bipartido)?;
+    Some(bipartido.get_vertices_x().into_iter().filter(|x| alcanzados.contains(x))
+            .chain(bipartido.get_vertices_y().into_iter().filter(|y| !alcanzados.contains(y)))
+            .collect())
+}
+
+///
+/// PRE: Grafo bipartido
+/// POST: None si existe un emparejamiento que satura la primera particion. Eoc, subconjunto S de la primera
+/// particion que incumple la condicion de Hall: tiene menos vecinos que vertices
+/// NOTA: S son los vertices de X alcanzables por caminos alternados desde los libres de un emparejamiento maximo
+/// 
+pub fn violacion_hall<Vertice, Peso>(bipartido: &Bipartido<Vertice, Peso>) -> Option<Vec<&Vertice>>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let emparejamiento = hopcroft_karp(bipartido);
+    if emparejamiento.size() == bipartido.get_vertices_x().len()
+    {
+        return None;
+    }
+    let alcanzados = alcanzables(&emparejamiento, bipartido).unwrap();
+    Some(bipartido.get_vertices_x().into_iter().filter(|x| alcanzados.contains(x)).collect())
+}
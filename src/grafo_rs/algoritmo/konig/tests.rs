@@ -0,0 +1,73 @@
+use crate::grafo_rs::{Arista, Bipartido, Emparejamiento, Grafo, NoPeso, hopcroft_karp};
+use super::*;
+
+fn trabajadores() -> Grafo<i32, NoPeso>
+{
+    // Los trabajadores 1, 2 y 3 solo pueden ocupar los puestos 10 y 11
+    Grafo::from_aristas([Arista::arista_sin_peso(1, 10),
+                         Arista::arista_sin_peso(2, 10),
+                         Arista::arista_sin_peso(3, 10),
+                         Arista::arista_sin_peso(3, 11),
+                         Arista::arista_sin_peso(4, 11),
+                         Arista::arista_sin_peso(4, 12),
+                         Arista::arista_sin_peso(5, 13),
+                         Arista::vertice(6)].to_vec())
+}
+
+#[test]
+fn test_cubrimiento_minimo()
+{
+    let g = trabajadores();
+    let bipartido = Bipartido::from_grafo(&g).unwrap();
+    let emparejamiento = hopcroft_karp(&bipartido);
+    let cubrimiento = cubrimiento_minimo(&emparejamiento, &bipartido).expect("Emparejamiento maximo");
+    assert_eq!(cubrimiento.len(), emparejamiento.size());
+    assert_eq!(cubrimiento.len(), 4);
+    assert!(bipartido.get_aristas().iter()
+                .filter_map(|x| x.get_vertices())
+                .all(|(x, y)| cubrimiento.contains(&x) || cubrimiento.contains(&y)));
+    assert!(!cubrimiento.contains(&&6));
+
+    let independiente = conjunto_independiente_maximo(&emparejamiento, &bipartido).unwrap();
+    assert_eq!(independiente.len(), bipartido.get_vertices().len() - emparejamiento.size());
+    assert!(independiente.contains(&&6));
+    assert!(bipartido.get_aristas().iter()
+                .filter_map(|x| x.get_vertices())
+                .all(|(x, y)| !(independiente.contains(&x) && independiente.contains(&y))));
+}
+
+#[test]
+fn test_cubrimiento_no_maximo()
+{
+    let g = trabajadores();
+    let bipartido = Bipartido::from_grafo(&g).unwrap();
+    let arista = bipartido.get_aristas().iter().find(|x| x.get_vertices() == Some((&1, &10))).unwrap();
+    let emparejamiento = Emparejamiento::new(vec![arista]).unwrap();
+    assert!(cubrimiento_minimo(&emparejamiento, &bipartido).is_none());
+    assert!(conjunto_independiente_maximo(&emparejamiento, &bipartido).is_none());
+}
+
+#[test]
+fn test_violacion_hall()
+{
+    let g = trabajadores();
+    let bipartido = Bipartido::from_grafo(&g).unwrap();
+    let mut s = violacion_hall(&bipartido).expect("No se puede saturar X");
+    s.sort();
+    // Los vecinos de S son menos que sus vertices
+    let vecinos: HashSet<&i32> = bipartido.get_aristas().iter()
+                                    .filter_map(|x| x.get_vertices())
+                                    .filter(|(x, _)| s.contains(x))
+                                    .map(|(_, y)| y)
+                                    .collect();
+    assert!(vecinos.len() < s.len());
+    assert!(s.contains(&&6));
+    assert!(s.iter().all(|x| bipartido.get_vertices_x().contains(x)));
+
+    // Con un puesto para cada trabajador se cumple la condicion
+    let completo: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(1, 10),
+                                                              Arista::arista_sin_peso(1, 11),
+                                                              Arista::arista_sin_peso(2, 10),
+                                                              Arista::arista_sin_peso(2, 12)].to_vec());
+    assert!(violacion_hall(&Bipartido::from_grafo(&completo).unwrap()).is_none());
+}
@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+
+use crate::grafo_rs::{Arbol, Arista, AristaT, Bosque, ConjuntosDisjuntos, Grafo, GrafoT, PesoT, VerticeT};
+
+#[cfg(test)]
+mod tests;
+
+///
+/// Funcion auxiliar. Bosque generador segun el orden de pesos indicado
+/// 
+fn kruskal<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>, maximo: bool) -> Option<Bosque<Grafo<Vertice, Peso>, Vertice, Peso>>
+where Vertice: VerticeT, Peso: PesoT + Ord
+{
+    let vertices = grafo.get_vertices();
+    let ids: HashMap<&Vertice, usize> = vertices.iter().enumerate()
+                                            .map(|(i, v)| (*v, i))
+                                            .collect();
+
+    // Ordenamos las aristas por su peso. Los vertices aislados y los lazos no forman parte del bosque
+    let mut aristas: Vec<(&Peso, usize, usize, &Arista<Vertice, Peso>)> = vec![];
+    for arista in grafo.get_aristas().iter()
+    {
+        if let Some((v, w)) = arista.get_vertices()
+        {
+            if v != w
+            {
+                aristas.push((arista.get_peso()?, ids[v], ids[w], arista));
+            }
+        }
+    }
+    match maximo {
+        true => aristas.sort_by(|x, y| y.0.cmp(x.0)),
+        false => aristas.sort_by(|x, y| x.0.cmp(y.0))
+    };
+
+    let mut conjuntos = ConjuntosDisjuntos::new(vertices.len());
+    let mut aristas_seleccionadas = vec![];
+    for (_, v, w, arista) in aristas.into_iter()
+    {
+        if conjuntos.unir(v, w)
+        {
+            aristas_seleccionadas.push((v, arista));
+        }
+    }
+
+    // Cada componente queda identificada por su representante. La raiz es su primer vertice
+    let mut componentes: HashMap<usize, usize> = HashMap::new();
+    let mut bosque: Vec<(Grafo<Vertice, Peso>, &Vertice)> = vec![];
+    for (id, v) in vertices.iter().enumerate()
+    {
+        let representante = conjuntos.buscar(id);
+        if let Entry::Vacant(entrada) = componentes.entry(representante)
+        {
+            entrada.insert(bosque.len());
+            bosque.push((Grafo::new(), v));
+        }
+    }
+    for (v, arista) in aristas_seleccionadas.into_iter()
+    {
+        let componente = componentes[&conjuntos.buscar(v)];
+        bosque[componente].0.add_aristas(vec![arista.clone()]);
+    }
+
+    let bosque = bosque.into_iter()
+                    .map(|(mut arbol, raiz)| {
+                        if arbol.get_aristas().is_empty()
+                        {
+                            arbol.add_vertices(vec![raiz.clone()]);
+                        }
+                        Arbol::from_grafo(arbol, raiz.clone())
+                    })
+                    .collect();
+    Some(bosque)
+}
+
+///
+/// PRE: Grafo
+/// POST: Bosque generador de peso minimo: un Arbol por cada componente conexa. None si falta algun peso
+/// NOTA: Implementacion del algoritmo de Kruskal. Los vertices aislados generan un Arbol sin aristas
+/// 
+pub fn bosque_peso_minimo<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>) -> Option<Bosque<Grafo<Vertice, Peso>, Vertice, Peso>>
+where Vertice: VerticeT, Peso: PesoT + Ord
+{
+    kruskal(grafo, false)
+}
+
+///
+/// PRE: Grafo
+/// POST: Bosque generador de peso maximo: un Arbol por cada componente conexa. None si falta algun peso
+/// NOTA: Implementacion del algoritmo de Kruskal. Los vertices aislados generan un Arbol sin aristas
+/// 
+pub fn bosque_peso_maximo<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>) -> Option<Bosque<Grafo<Vertice, Peso>, Vertice, Peso>>
+where Vertice: VerticeT, Peso: PesoT + Ord
+{
+    kruskal(grafo, true)
+}
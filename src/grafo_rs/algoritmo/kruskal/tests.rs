@@ -0,0 +1,58 @@
+use crate::grafo_rs::{Arista, AristaT, Grafo, GrafoT, arbol_peso_minimo};
+use super::*;
+
+#[test]
+fn test_bosque_coincide_con_prim()
+{
+    let g: Grafo<i32, isize> = Grafo::from_aristas([Arista::arista(4, 5, Some(15)),
+                                                        Arista::arista(1, 2, Some(7)),
+                                                        Arista::arista(2, 3, Some(8)),
+                                                        Arista::arista(3, 5, Some(5)),
+                                                        Arista::arista(1, 4, Some(5)),
+                                                        Arista::arista(2, 4, Some(9)),
+                                                        Arista::arista(2, 5, Some(7)),
+                                                        Arista::arista(4, 6, Some(6)),
+                                                        Arista::arista(5, 6, Some(8)),
+                                                        Arista::arista(6, 7, Some(11)),
+                                                        Arista::arista(5, 7, Some(9))].to_vec());
+    let bosque = bosque_peso_minimo(&g).expect("Todas las aristas tienen peso");
+    assert_eq!(bosque.len(), 1);
+    let prim = arbol_peso_minimo(&g).expect("El arbol existe");
+    assert_eq!(Arista::sumatorio_pesos(bosque[0].get_aristas()), Arista::sumatorio_pesos(prim.get_aristas()));
+    assert_eq!(bosque[0].get_aristas().len(), 6);
+    assert_eq!(bosque[0].raiz(), &4);
+}
+
+#[test]
+fn test_bosque_grafo_no_conexo()
+{
+    let g: Grafo<char, usize> = Grafo::from_aristas([Arista::arista('a', 'b', Some(4)),
+                                                        Arista::arista('b', 'c', Some(1)),
+                                                        Arista::arista('a', 'c', Some(2)),
+                                                        Arista::arista('x', 'y', Some(3)),
+                                                        Arista::arista('y', 'z', Some(5)),
+                                                        Arista::arista('x', 'z', Some(7)),
+                                                        Arista::arista('z', 'z', Some(0)),
+                                                        Arista::vertice('q')].to_vec());
+    let minimo = bosque_peso_minimo(&g).expect("Todas las aristas tienen peso");
+    assert_eq!(minimo.len(), 3, "Una componente por arbol");
+    assert_eq!(Arista::sumatorio_pesos(minimo[0].get_aristas()), 3);
+    assert_eq!(Arista::sumatorio_pesos(minimo[1].get_aristas()), 8);
+    assert_eq!(minimo[2].get_vertices(), vec![&'q']);
+    assert_eq!(minimo[2].raiz(), &'q');
+
+    let maximo = bosque_peso_maximo(&g).expect("Todas las aristas tienen peso");
+    assert_eq!(maximo.len(), 3);
+    assert_eq!(Arista::sumatorio_pesos(maximo[0].get_aristas()), 6);
+    assert_eq!(Arista::sumatorio_pesos(maximo[1].get_aristas()), 12);
+    assert!(!maximo[1].get_aristas().contains(&Arista::arista('z', 'z', Some(0))), "Los lazos no forman parte del bosque");
+}
+
+#[test]
+fn test_bosque_sin_pesos()
+{
+    let g: Grafo<i32, isize> = Grafo::from_aristas([Arista::arista(1, 2, Some(1)),
+                                                        Arista::arista_sin_peso(2, 3)].to_vec());
+    assert!(bosque_peso_minimo(&g).is_none());
+    assert!(bosque_peso_minimo(&Grafo::<i32, isize>::new()).expect("El grafo vacio tiene un bosque vacio").is_empty());
+}
@@ -0,0 +1,80 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::grafo_rs::{AristaT, Diarista, Digrafo, GrafoT, PesoT, VerticeT};
+
+#[cfg(test)]
+mod tests;
+
+///
+/// PRE: Digrafo
+/// POST: Si el digrafo es aciclico, vector con sus vertices en orden topologico. Eoc, los arcos
+/// de un ciclo dirigido en orden de recorrido, que prueban que no es aciclico
+/// NOTA: Implementacion del algoritmo de Kahn
+/// 
+pub fn orden_topologico<Vertice, Peso>(digrafo: &Digrafo<Vertice, Peso>) -> Result<Vec<&Vertice>, Vec<&Diarista<Vertice, Peso>>>
+where Vertice: VerticeT, Peso: PesoT
+{
+    let vertices = digrafo.get_vertices();
+    let n = vertices.len();
+    let ids: HashMap<&Vertice, usize> = vertices.iter().enumerate()
+                                            .map(|(i, v)| (*v, i))
+                                            .collect();
+    let mut grados_entrada: Vec<usize> = vertices.iter()
+                        .map(|v| digrafo.grado_entrada(v).unwrap())
+                        .collect();
+
+    let mut cola: VecDeque<usize> = (0..n).filter(|x| grados_entrada[*x] == 0).collect();
+    let mut orden: Vec<&Vertice> = Vec::with_capacity(n);
+    while let Some(id) = cola.pop_front()
+    {
+        let v = vertices[id];
+        orden.push(v);
+        for arco in digrafo.aristas_accesibles(v)
+        {
+            let w = ids[arco.get_vertices().unwrap().1];
+            grados_entrada[w] -= 1;
+            if grados_entrada[w] == 0
+            {
+                cola.push_back(w);
+            }
+        }
+    }
+    if orden.len() == n
+    {
+        return Ok(orden);
+    }
+
+    /*
+     * Los vertices restantes tienen algun arco entrante desde otro vertice restante.
+     * Retrocedemos por esos arcos hasta repetir un vertice: hemos encontrado un ciclo
+     */
+    let restante = |v: &Vertice| grados_entrada[ids[v]] > 0;
+    let arco_entrante = |v: &Vertice| digrafo.aristas_por_vertice(v).into_iter()
+                        .find(|x| x.get_vertices().unwrap().1 == v && restante(x.get_vertices().unwrap().0))
+                        .unwrap();
+    let inicial = (0..n).find(|x| grados_entrada[*x] > 0).unwrap();
+    let mut visitados: Vec<Option<usize>> = vec![None; n];
+    let mut recorrido: Vec<&Diarista<Vertice, Peso>> = vec![];
+    let mut actual = inicial;
+    while visitados[actual].is_none()
+    {
+        visitados[actual] = Some(recorrido.len());
+        let arco = arco_entrante(vertices[actual]);
+        recorrido.push(arco);
+        actual = ids[arco.get_vertices().unwrap().0];
+    }
+    // El ciclo lo forman los arcos recorridos desde la primera visita de `actual`
+    let mut ciclo = recorrido.split_off(visitados[actual].unwrap());
+    ciclo.reverse();
+    Err(ciclo)
+}
+
+///
+/// PRE: Digrafo
+/// POST: true si el digrafo no tiene ciclos dirigidos. false eoc
+/// 
+pub fn es_aciclico<Vertice, Peso>(digrafo: &Digrafo<Vertice, Peso>) -> bool
+where Vertice: VerticeT, Peso: PesoT
+{
+    orden_topologico(digrafo).is_ok()
+}
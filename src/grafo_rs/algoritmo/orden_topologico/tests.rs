@@ -0,0 +1,56 @@
+use crate::grafo_rs::{AristaT, Diarista, Digrafo, GrafoT, NoPeso};
+use super::*;
+
+#[test]
+fn test_orden_topologico()
+{
+    let tareas: Digrafo<String> = Digrafo::from_aristas([
+                                    Diarista::arista_sin_peso("calzoncillos".to_owned(), "pantalones".to_owned()),
+                                    Diarista::arista_sin_peso("calzoncillos".to_owned(), "zapatos".to_owned()),
+                                    Diarista::arista_sin_peso("pantalones".to_owned(), "cinturon".to_owned()),
+                                    Diarista::arista_sin_peso("pantalones".to_owned(), "zapatos".to_owned()),
+                                    Diarista::arista_sin_peso("camisa".to_owned(), "cinturon".to_owned()),
+                                    Diarista::arista_sin_peso("camisa".to_owned(), "corbata".to_owned()),
+                                    Diarista::arista_sin_peso("corbata".to_owned(), "chaqueta".to_owned()),
+                                    Diarista::arista_sin_peso("cinturon".to_owned(), "chaqueta".to_owned()),
+                                    Diarista::arista_sin_peso("calcetines".to_owned(), "zapatos".to_owned()),
+                                    Diarista::vertice("reloj".to_owned())].to_vec());
+    let orden = match orden_topologico(&tareas) {
+        Ok(orden) => orden,
+        Err(_) => panic!("El digrafo es aciclico")
+    };
+    assert_eq!(orden.len(), tareas.size());
+    let posicion = |v: &String| orden.iter().position(|x| *x == v).unwrap();
+    for arco in tareas.get_aristas()
+    {
+        if let Some((u, v)) = arco.get_vertices()
+        {
+            assert!(posicion(u) < posicion(v), "Los arcos deben respetar el orden");
+        }
+    }
+    assert!(es_aciclico(&tareas));
+}
+
+#[test]
+fn test_orden_topologico_ciclo()
+{
+    let dg: Digrafo<i32, NoPeso> = Digrafo::from_aristas([Diarista::arista_sin_peso(1, 2),
+                                                            Diarista::arista_sin_peso(2, 3),
+                                                            Diarista::arista_sin_peso(3, 4),
+                                                            Diarista::arista_sin_peso(4, 2),
+                                                            Diarista::arista_sin_peso(4, 5),
+                                                            Diarista::arista_sin_peso(6, 1)].to_vec());
+    assert!(!es_aciclico(&dg));
+    let ciclo = orden_topologico(&dg).expect_err("El digrafo tiene un ciclo");
+    assert_eq!(ciclo.len(), 3);
+    for i in 0..ciclo.len()
+    {
+        let siguiente = ciclo[(i + 1) % ciclo.len()];
+        assert_eq!(ciclo[i].get_vertices().unwrap().1, siguiente.get_vertices().unwrap().0);
+    }
+
+    let lazo: Digrafo<i32, NoPeso> = Digrafo::from_aristas([Diarista::arista_sin_peso(1, 2),
+                                                            Diarista::arista_sin_peso(2, 2)].to_vec());
+    let ciclo = orden_topologico(&lazo).expect_err("El lazo es un ciclo");
+    assert!(ciclo == vec![&Diarista::arista_sin_peso(2, 2)]);
+}
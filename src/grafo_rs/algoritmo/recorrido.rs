@@ -0,0 +1,359 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::ptr;
+
+use crate::grafo_rs::{AristaT, GrafoT, PesoT, VerticeT};
+
+#[cfg(test)]
+mod tests;
+
+///
+/// Indica a los recorridos si deben continuar o detenerse
+/// 
+pub enum Control
+{
+    Continuar,
+    Parar
+}
+
+impl Clone for Control
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for Control
+{}
+
+impl PartialEq for Control
+{
+    fn eq(&self, other: &Self) -> bool {
+        matches!((self, other), (Control::Continuar, Control::Continuar) | (Control::Parar, Control::Parar))
+    }
+}
+
+impl Debug for Control
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Control::Continuar => write!(f, "Continuar"),
+            Control::Parar => write!(f, "Parar")
+        }
+    }
+}
+
+///
+/// Trait con las acciones a realizar durante un recorrido. Todas devuelven `Control::Continuar` por defecto.
+/// Si alguna devuelve `Control::Parar`, el recorrido termina inmediatamente
+/// NOTA: Las aristas se reciben junto con el vertice desde el que se recorren
+/// 
+pub trait Visitante<'a, Vertice, Arista>
+{
+    ///
+    /// Se llama la primera vez que se alcanza un vertice
+    /// 
+    fn descubrir_vertice(&mut self, _: &'a Vertice) -> Control
+    {
+        Control::Continuar
+    }
+
+    ///
+    /// Se llama cuando se han recorrido todas las aristas accesibles del vertice
+    /// 
+    fn finalizar_vertice(&mut self, _: &'a Vertice) -> Control
+    {
+        Control::Continuar
+    }
+
+    ///
+    /// Se llama con las aristas que descubren un vertice nuevo
+    /// 
+    fn arista_arbol(&mut self, _: &'a Vertice, _: &'a Arista) -> Control
+    {
+        Control::Continuar
+    }
+
+    ///
+    /// Se llama con las aristas que llegan a un antecesor en el arbol del recorrido, incluidos los lazos
+    /// 
+    fn arista_retroceso(&mut self, _: &'a Vertice, _: &'a Arista) -> Control
+    {
+        Control::Continuar
+    }
+
+    ///
+    /// Se llama con los arcos que llegan a un descendiente ya finalizado. Solo se dan en profundidad sobre digrafos
+    /// 
+    fn arista_avance(&mut self, _: &'a Vertice, _: &'a Arista) -> Control
+    {
+        Control::Continuar
+    }
+
+    ///
+    /// Se llama con el resto de aristas que no pertenecen al arbol del recorrido
+    /// 
+    fn arista_cruce(&mut self, _: &'a Vertice, _: &'a Arista) -> Control
+    {
+        Control::Continuar
+    }
+}
+
+///
+/// Iterador perezoso que devuelve los vertices alcanzables desde una raiz por orden de busqueda en anchura
+/// 
+pub struct Bfs<'a, Graf, Vertice, Peso>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
+{
+    grafo: &'a Graf,
+    cola: VecDeque<&'a Vertice>,
+    vertices_visitados: HashSet<&'a Vertice>,
+    p: PhantomData<Peso>
+}
+
+impl<'a, Graf, Vertice, Peso> Bfs<'a, Graf, Vertice, Peso>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
+{
+    ///
+    /// PRE: Grafo y vertice raiz
+    /// POST: Iterador en anchura desde la raiz. None si no esta en el grafo
+    /// 
+    pub fn new(grafo: &'a Graf, v0: &Vertice) -> Option<Self>
+    {
+        let raiz = *grafo.get_vertices().iter().find(|x| **x == v0)?;
+        Some(Self {
+            grafo,
+            cola: VecDeque::from([raiz]),
+            vertices_visitados: HashSet::from([raiz]),
+            p: PhantomData
+        })
+    }
+}
+
+impl<'a, Graf, Vertice, Peso> Iterator for Bfs<'a, Graf, Vertice, Peso>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT, Graf::Arista: 'a
+{
+    type Item = &'a Vertice;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let vertice = self.cola.pop_front()?;
+        for arista in self.grafo.aristas_accesibles(vertice)
+        {
+            let otro = arista.other(vertice).unwrap();
+            if self.vertices_visitados.insert(otro)
+            {
+                self.cola.push_back(otro);
+            }
+        }
+        Some(vertice)
+    }
+}
+
+///
+/// Iterador perezoso que devuelve los vertices alcanzables desde una raiz por orden de descubrimiento en profundidad
+/// 
+pub struct Dfs<'a, Graf, Vertice, Peso>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
+{
+    grafo: &'a Graf,
+    raiz: Option<&'a Vertice>,
+    /*
+     * Pila de backtracking con cada vertice abierto y sus aristas pendientes de explorar
+     */
+    pila: Vec<(&'a Vertice, std::vec::IntoIter<&'a Graf::Arista>)>,
+    vertices_visitados: HashSet<&'a Vertice>,
+    p: PhantomData<Peso>
+}
+
+impl<'a, Graf, Vertice, Peso> Dfs<'a, Graf, Vertice, Peso>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
+{
+    ///
+    /// PRE: Grafo y vertice raiz
+    /// POST: Iterador en profundidad desde la raiz. None si no esta en el grafo
+    /// 
+    pub fn new(grafo: &'a Graf, v0: &Vertice) -> Option<Self>
+    {
+        let raiz = *grafo.get_vertices().iter().find(|x| **x == v0)?;
+        Some(Self {
+            grafo,
+            raiz: Some(raiz),
+            pila: vec![],
+            vertices_visitados: HashSet::from([raiz]),
+            p: PhantomData
+        })
+    }
+}
+
+impl<'a, Graf, Vertice, Peso> Iterator for Dfs<'a, Graf, Vertice, Peso>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT, Graf::Arista: 'a
+{
+    type Item = &'a Vertice;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(raiz) = self.raiz.take()
+        {
+            self.pila.push((raiz, self.grafo.aristas_accesibles(raiz).into_iter()));
+            return Some(raiz);
+        }
+        while let Some((vertice, aristas_pendientes)) = self.pila.last_mut()
+        {
+            let vertice = *vertice;
+            let siguiente = aristas_pendientes
+                                .find(|x| !self.vertices_visitados.contains(x.other(vertice).unwrap()));
+            match siguiente {
+                Some(arista) => {
+                    let otro = arista.other(vertice).unwrap();
+                    self.vertices_visitados.insert(otro);
+                    self.pila.push((otro, self.grafo.aristas_accesibles(otro).into_iter()));
+                    return Some(otro);
+                },
+                None => { self.pila.pop(); }
+            }
+        }
+        None
+    }
+}
+
+///
+/// PRE: Arbol de recorrido dado por el padre de cada vertice y par de vertices descubiertos
+/// POST: true si `w` es antecesor de `v` o coincide con el. false eoc
+/// 
+fn es_antecesor<'a, Vertice>(padres: &HashMap<&'a Vertice, Option<&'a Vertice>>, w: &Vertice, v: &'a Vertice) -> bool
+where Vertice: VerticeT
+{
+    let mut actual = Some(v);
+    while let Some(u) = actual
+    {
+        if u == w
+        {
+            return true;
+        }
+        actual = padres[u];
+    }
+    false
+}
+
+///
+/// PRE: Grafo, vertice raiz y visitante
+/// POST: Se recorre en anchura desde la raiz avisando al visitante. Devuelve `Control::Parar` si el visitante
+/// detuvo el recorrido y `Control::Continuar` si termino. None si la raiz no esta en el grafo
+/// NOTA: Cada arista se clasifica una unica vez. En anchura no hay aristas de avance
+/// 
+pub fn visitar_anchura<'a, Graf, Vertice, Peso, Vis>(grafo: &'a Graf, v0: &Vertice, visitante: &mut Vis) -> Option<Control>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT + 'a, Peso: PesoT, Graf::Arista: 'a,
+    Vis: Visitante<'a, Vertice, Graf::Arista>
+{
+    let raiz = *grafo.get_vertices().iter().find(|x| **x == v0)?;
+    let mut padres: HashMap<&Vertice, Option<&Vertice>> = HashMap::from([(raiz, None)]);
+    let mut vertices_finalizados: HashSet<&Vertice> = HashSet::new();
+    let mut cola: VecDeque<&Vertice> = VecDeque::from([raiz]);
+
+    if visitante.descubrir_vertice(raiz) == Control::Parar
+    {
+        return Some(Control::Parar);
+    }
+    while let Some(vertice) = cola.pop_front()
+    {
+        for arista in grafo.aristas_accesibles(vertice)
+        {
+            let otro = arista.other(vertice).unwrap();
+            let control = if !padres.contains_key(otro)
+            {
+                padres.insert(otro, Some(vertice));
+                cola.push_back(otro);
+                match visitante.arista_arbol(vertice, arista) {
+                    Control::Continuar => visitante.descubrir_vertice(otro),
+                    Control::Parar => Control::Parar
+                }
+            }
+            // Las aristas no dirigidas ya se clasificaron desde el otro extremo
+            else if arista.es_accesible(otro) && vertices_finalizados.contains(otro)
+            {
+                Control::Continuar
+            }
+            else if es_antecesor(&padres, otro, vertice)
+            {
+                visitante.arista_retroceso(vertice, arista)
+            }
+            else
+            {
+                visitante.arista_cruce(vertice, arista)
+            };
+            if control == Control::Parar
+            {
+                return Some(Control::Parar);
+            }
+        }
+        vertices_finalizados.insert(vertice);
+        if visitante.finalizar_vertice(vertice) == Control::Parar
+        {
+            return Some(Control::Parar);
+        }
+    }
+    Some(Control::Continuar)
+}
+
+///
+/// PRE: Grafo, vertice raiz y visitante
+/// POST: Se recorre en profundidad desde la raiz avisando al visitante. Devuelve `Control::Parar` si el visitante
+/// detuvo el recorrido y `Control::Continuar` si termino. None si la raiz no esta en el grafo
+/// NOTA: Cada arista se clasifica una unica vez. La arista por la que se descubre un vertice no se vuelve a
+/// recorrer desde el, aunque sea no dirigida
+/// 
+pub fn visitar_profundidad<'a, Graf, Vertice, Peso, Vis>(grafo: &'a Graf, v0: &Vertice, visitante: &mut Vis) -> Option<Control>
+where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT + 'a, Peso: PesoT, Graf::Arista: 'a,
+    Vis: Visitante<'a, Vertice, Graf::Arista>
+{
+    let raiz = *grafo.get_vertices().iter().find(|x| **x == v0)?;
+    let mut descubrimiento: HashMap<&Vertice, usize> = HashMap::from([(raiz, 0)]);
+    let mut vertices_finalizados: HashSet<&Vertice> = HashSet::new();
+    // Pila con cada vertice abierto, la arista por la que se descubrio y sus aristas pendientes
+    let mut pila = vec![(raiz, None, grafo.aristas_accesibles(raiz).into_iter())];
+
+    if visitante.descubrir_vertice(raiz) == Control::Parar
+    {
+        return Some(Control::Parar);
+    }
+    while let Some((vertice, arista_previa, aristas_pendientes)) = pila.last_mut()
+    {
+        let vertice = *vertice;
+        let arista_previa: Option<&Graf::Arista> = *arista_previa;
+        let control = match aristas_pendientes.next() {
+            Some(arista) => {
+                let otro = arista.other(vertice).unwrap();
+                let no_dirigida = arista.es_accesible(otro);
+                if arista_previa.is_some_and(|x| ptr::eq(x, arista))
+                {
+                    continue;
+                }
+                match descubrimiento.get(otro) {
+                    None => {
+                        descubrimiento.insert(otro, descubrimiento.len());
+                        pila.push((otro, Some(arista), grafo.aristas_accesibles(otro).into_iter()));
+                        match visitante.arista_arbol(vertice, arista) {
+                            Control::Continuar => visitante.descubrir_vertice(otro),
+                            Control::Parar => Control::Parar
+                        }
+                    },
+                    Some(_) if !vertices_finalizados.contains(otro) => visitante.arista_retroceso(vertice, arista),
+                    // Las aristas no dirigidas ya se clasificaron desde el otro extremo
+                    Some(_) if no_dirigida => Control::Continuar,
+                    Some(t) if descubrimiento[vertice] < *t => visitante.arista_avance(vertice, arista),
+                    Some(_) => visitante.arista_cruce(vertice, arista)
+                }
+            },
+            None => {
+                pila.pop();
+                vertices_finalizados.insert(vertice);
+                visitante.finalizar_vertice(vertice)
+            }
+        };
+        if control == Control::Parar
+        {
+            return Some(Control::Parar);
+        }
+    }
+    Some(Control::Continuar)
+}
@@ -0,0 +1,173 @@
+use crate::grafo_rs::{Arista, Diarista, AristaT, Grafo, Digrafo, GrafoT, NoPeso};
+use super::*;
+
+///
+/// Visitante que anota los sucesos del recorrido y se detiene al descubrir el objetivo
+/// 
+struct Registro
+{
+    sucesos: Vec<String>,
+    objetivo: Option<char>
+}
+
+impl Registro
+{
+    fn new(objetivo: Option<char>) -> Self
+    {
+        Self { sucesos: vec![], objetivo }
+    }
+
+    fn contar(&self, tipo: &str) -> usize
+    {
+        self.sucesos.iter().filter(|x| x.starts_with(tipo)).count()
+    }
+}
+
+impl<'a, A> Visitante<'a, char, A> for Registro
+where A: AristaT<char, NoPeso>
+{
+    fn descubrir_vertice(&mut self, v: &'a char) -> Control
+    {
+        self.sucesos.push(format!("D {}", v));
+        match self.objetivo == Some(*v) {
+            true => Control::Parar,
+            false => Control::Continuar
+        }
+    }
+
+    fn finalizar_vertice(&mut self, v: &'a char) -> Control
+    {
+        self.sucesos.push(format!("F {}", v));
+        Control::Continuar
+    }
+
+    fn arista_arbol(&mut self, v: &'a char, arista: &'a A) -> Control
+    {
+        self.sucesos.push(format!("T {}{}", v, arista.other(v).unwrap()));
+        Control::Continuar
+    }
+
+    fn arista_retroceso(&mut self, v: &'a char, arista: &'a A) -> Control
+    {
+        self.sucesos.push(format!("R {}{}", v, arista.other(v).unwrap()));
+        Control::Continuar
+    }
+
+    fn arista_avance(&mut self, v: &'a char, arista: &'a A) -> Control
+    {
+        self.sucesos.push(format!("A {}{}", v, arista.other(v).unwrap()));
+        Control::Continuar
+    }
+
+    fn arista_cruce(&mut self, v: &'a char, arista: &'a A) -> Control
+    {
+        self.sucesos.push(format!("C {}{}", v, arista.other(v).unwrap()));
+        Control::Continuar
+    }
+}
+
+#[test]
+fn test_iteradores()
+{
+    let g: Grafo<char> = Grafo::from_aristas([Arista::arista_sin_peso('a', 'b'),
+                                                    Arista::arista_sin_peso('a', 'c'),
+                                                    Arista::arista_sin_peso('b', 'd'),
+                                                    Arista::arista_sin_peso('c', 'd'),
+                                                    Arista::arista_sin_peso('d', 'e'),
+                                                    Arista::vertice('z')].to_vec());
+
+    let anchura: Vec<&char> = Bfs::new(&g, &'a').expect("La raiz esta en el grafo").collect();
+    assert_eq!(anchura, vec![&'a', &'b', &'c', &'d', &'e']);
+
+    let profundidad: Vec<&char> = Dfs::new(&g, &'a').expect("La raiz esta en el grafo").collect();
+    assert_eq!(profundidad, vec![&'a', &'b', &'d', &'c', &'e']);
+
+    // Los iteradores son perezosos y pueden combinarse
+    assert_eq!(Dfs::new(&g, &'a').unwrap().position(|x| *x == 'c'), Some(3));
+    assert_eq!(Bfs::new(&g, &'z').unwrap().count(), 1);
+    assert!(Bfs::new(&g, &'x').is_none());
+    assert!(Dfs::new(&g, &'x').is_none());
+
+    let digrafo: Digrafo<char> = Digrafo::from_aristas([Diarista::arista_sin_peso('a', 'b'),
+                                                            Diarista::arista_sin_peso('c', 'a')].to_vec());
+    assert_eq!(Dfs::new(&digrafo, &'a').unwrap().collect::<Vec<&char>>(), vec![&'a', &'b']);
+}
+
+#[test]
+fn test_clasificacion_profundidad_digrafo()
+{
+    let digrafo: Digrafo<char> = Digrafo::from_aristas([Diarista::arista_sin_peso('a', 'b'),
+                                                            Diarista::arista_sin_peso('b', 'c'),
+                                                            Diarista::arista_sin_peso('c', 'a'),
+                                                            Diarista::arista_sin_peso('a', 'c'),
+                                                            Diarista::arista_sin_peso('a', 'd'),
+                                                            Diarista::arista_sin_peso('d', 'c'),
+                                                            Diarista::arista_sin_peso('c', 'c')].to_vec());
+
+    let mut registro = Registro::new(None);
+    assert_eq!(visitar_profundidad(&digrafo, &'a', &mut registro), Some(Control::Continuar));
+    assert_eq!(registro.sucesos, vec!["D a", "T ab", "D b", "T bc", "D c", "R ca", "R cc", "F c", "F b",
+                                      "A ac", "T ad", "D d", "C dc", "F d", "F a"]);
+}
+
+#[test]
+fn test_clasificacion_grafo()
+{
+    let g: Grafo<char> = Grafo::from_aristas([Arista::arista_sin_peso('a', 'b'),
+                                                    Arista::arista_sin_peso('b', 'c'),
+                                                    Arista::arista_sin_peso('c', 'd'),
+                                                    Arista::arista_sin_peso('d', 'a'),
+                                                    Arista::arista_sin_peso('a', 'c'),
+                                                    Arista::arista_sin_peso('d', 'd')].to_vec());
+
+    // Cada arista se clasifica una unica vez y en un grafo no hay aristas de avance ni de cruce en profundidad
+    let mut registro = Registro::new(None);
+    visitar_profundidad(&g, &'a', &mut registro).expect("La raiz esta en el grafo");
+    assert_eq!(registro.contar("T"), 3);
+    assert_eq!(registro.contar("R"), 3);
+    assert_eq!(registro.contar("A") + registro.contar("C"), 0);
+    assert_eq!(registro.contar("F"), 4);
+
+    // En anchura las aristas no arbol son de cruce, salvo los lazos
+    let mut registro = Registro::new(None);
+    visitar_anchura(&g, &'a', &mut registro).expect("La raiz esta en el grafo");
+    assert_eq!(registro.sucesos, vec!["D a", "T ab", "D b", "T ad", "D d", "T ac", "D c", "F a",
+                                      "C bc", "F b", "C dc", "R dd", "F d", "F c"]);
+}
+
+#[test]
+fn test_clasificacion_anchura_digrafo()
+{
+    let digrafo: Digrafo<char> = Digrafo::from_aristas([Diarista::arista_sin_peso('a', 'b'),
+                                                            Diarista::arista_sin_peso('a', 'c'),
+                                                            Diarista::arista_sin_peso('b', 'c'),
+                                                            Diarista::arista_sin_peso('c', 'a')].to_vec());
+
+    let mut registro = Registro::new(None);
+    visitar_anchura(&digrafo, &'a', &mut registro).expect("La raiz esta en el grafo");
+    assert_eq!(registro.sucesos, vec!["D a", "T ab", "D b", "T ac", "D c", "F a",
+                                      "C bc", "F b", "R ca", "F c"]);
+}
+
+#[test]
+fn test_parada_anticipada()
+{
+    let g: Grafo<char> = Grafo::from_aristas([Arista::arista_sin_peso('a', 'b'),
+                                                    Arista::arista_sin_peso('b', 'c'),
+                                                    Arista::arista_sin_peso('c', 'd'),
+                                                    Arista::arista_sin_peso('a', 'e')].to_vec());
+
+    let mut registro = Registro::new(Some('c'));
+    assert_eq!(visitar_profundidad(&g, &'a', &mut registro), Some(Control::Parar));
+    assert_eq!(registro.sucesos.last().map(|x| x.as_str()), Some("D c"));
+    assert!(!registro.sucesos.contains(&"D d".to_string()));
+    assert!(!registro.sucesos.contains(&"D e".to_string()));
+
+    let mut registro = Registro::new(Some('e'));
+    assert_eq!(visitar_anchura(&g, &'a', &mut registro), Some(Control::Parar));
+    assert!(!registro.sucesos.contains(&"D c".to_string()));
+
+    let mut registro = Registro::new(None);
+    assert_eq!(visitar_anchura(&g, &'x', &mut registro), None);
+    assert!(registro.sucesos.is_empty());
+}
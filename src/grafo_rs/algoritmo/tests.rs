@@ -256,3 +256,95 @@ fn test_dijkstra_3()
     assert_eq!(min.buscar_vertice(&3).unwrap().get_valor(), 6);
     assert_eq!(min.buscar_vertice(&4).unwrap().get_valor(), 2);
 }
+
+#[test]
+fn test_dijkstra_rejilla()
+{
+    // Rejilla de 100x100 en la que las aristas horizontales pesan 1 y las verticales 2
+    let n: usize = 100;
+    let mut aristas = vec![];
+    for i in 0..n
+    {
+        for j in 0..n
+        {
+            if j + 1 < n
+            {
+                aristas.push(Arista::arista(i * n + j, i * n + j + 1, Some(1)));
+            }
+            if i + 1 < n
+            {
+                aristas.push(Arista::arista(i * n + j, (i + 1) * n + j, Some(2)));
+            }
+        }
+    }
+    let g: Grafo<usize, usize> = Grafo::from_aristas(aristas);
+    let (arbol, distancias) = arbol_camino_minimo(&g, &0).expect("El arbol debe existir");
+    assert_eq!(arbol.get_aristas().len(), n * n - 1);
+    assert_eq!(distancias.buscar_vertice(&(n * n - 1)).unwrap().get_valor(), 3 * (n as isize - 1));
+    assert_eq!(distancias.buscar_vertice(&(n - 1)).unwrap().get_valor(), n as isize - 1);
+}
+
+#[test]
+fn test_dijkstra_casos_invalidos()
+{
+    let g: Grafo<i32, isize> = Grafo::from_aristas([Arista::arista(1, 2, Some(3)),
+                                                            Arista::arista(2, 3, Some(-1))].to_vec());
+    assert!(arbol_camino_minimo(&g, &10).is_none(), "El vertice no esta en el grafo");
+    assert!(arbol_camino_minimo(&g, &1).is_none(), "Hay un peso negativo alcanzable");
+
+    let g: Grafo<i32, isize> = Grafo::from_aristas([Arista::arista(1, 2, Some(3)),
+                                                            Arista::arista(2, 3, None)].to_vec());
+    assert!(arbol_camino_minimo(&g, &1).is_none(), "Falta un peso");
+}
+
+#[test]
+fn test_recorridos_digrafo()
+{
+    let digrafo: Digrafo<char> = Digrafo::from_aristas([Diarista::arista_sin_peso('a', 'b'),
+                                                            Diarista::arista_sin_peso('b', 'c'),
+                                                            Diarista::arista_sin_peso('a', 'd'),
+                                                            Diarista::arista_sin_peso('d', 'c'),
+                                                            Diarista::arista_sin_peso('e', 'a'),
+                                                            Diarista::arista_sin_peso('c', 'e')].to_vec());
+
+    let anchura = arbol_anchura(&digrafo, &'a').expect("El arbol debe existir");
+    assert!(anchura.get_aristas() == &vec![Diarista::arista_sin_peso('a', 'b'),
+                                            Diarista::arista_sin_peso('a', 'd'),
+                                            Diarista::arista_sin_peso('b', 'c'),
+                                            Diarista::arista_sin_peso('c', 'e')]);
+
+    let (prof, df) = arbol_profundidad(&digrafo, &'a').expect("El arbol debe existir");
+    assert!(prof.get_aristas() == &vec![Diarista::arista_sin_peso('a', 'b'),
+                                        Diarista::arista_sin_peso('b', 'c'),
+                                        Diarista::arista_sin_peso('c', 'e'),
+                                        Diarista::arista_sin_peso('a', 'd')]);
+    let orden: Vec<(char, isize)> = df.get_datos().iter()
+                                        .map(|x| (*x.get_vertice(), x.get_valor()))
+                                        .collect();
+    assert_eq!(orden, vec![('a', 0), ('b', 1), ('c', 2), ('e', 3), ('d', 4)]);
+
+    // Desde un sumidero solo se alcanza el propio vertice
+    let sumidero: Digrafo<char> = Digrafo::from_aristas([Diarista::arista_sin_peso('a', 'b')].to_vec());
+    let anchura = arbol_anchura(&sumidero, &'b').expect("El arbol debe existir");
+    assert!(anchura.get_aristas() == &vec![Diarista::vertice('b')]);
+    assert!(arbol_profundidad(&sumidero, &'z').is_none());
+}
+
+#[test]
+fn test_alcanzables()
+{
+    let digrafo: Digrafo<i32> = Digrafo::from_aristas([Diarista::arista_sin_peso(1, 2),
+                                                            Diarista::arista_sin_peso(2, 3),
+                                                            Diarista::arista_sin_peso(4, 1),
+                                                            Diarista::arista_sin_peso(3, 2),
+                                                            Diarista::vertice(5)].to_vec());
+
+    assert_eq!(alcanzables(&digrafo, &1), Some(vec![&1, &2, &3]));
+    assert_eq!(alcanzables(&digrafo, &3), Some(vec![&3, &2]));
+    assert_eq!(alcanzables(&digrafo, &4), Some(vec![&4, &1, &2, &3]));
+    assert_eq!(alcanzables(&digrafo, &5), Some(vec![&5]));
+    assert_eq!(alcanzables(&digrafo, &6), None);
+
+    let grafo = digrafo.grafo_subyacente();
+    assert_eq!(alcanzables(&grafo, &3).map(|x| x.len()), Some(4), "En el grafo subyacente se ignora el sentido");
+}
@@ -0,0 +1,386 @@
+use std::collections::HashMap;
+
+use crate::grafo_rs::{Arista, AristaT, Grafo, GrafoT, PesoT, VerticeT};
+use crate::grafo_rs::algoritmo::bosque_peso_minimo;
+use crate::grafo_rs::algoritmo::cartero::emparejamiento_minimo;
+use crate::grafo_rs::algoritmo::euler::hierholzer;
+
+#[cfg(test)]
+mod tests;
+
+///
+/// Ruta del viajante: vertices en orden de visita, volviendo del ultimo al primero, y coste total
+/// 
+pub type RutaViajante<'a, Vertice, Peso> = (Vec<&'a Vertice>, Peso);
+
+///
+/// Estructura auxiliar con los vertices del grafo, sus identificadores y la arista de menor peso entre cada par.
+/// La matriz de aristas es densa: ocupa O(n^2) memoria aunque el grafo tenga pocas aristas
+/// 
+struct Instancia<'a, Vertice, Peso>
+where Vertice: VerticeT, Peso: PesoT
+{
+    vertices: Vec<&'a Vertice>,
+    ids: HashMap<&'a Vertice, usize>,
+    mejores: Vec<Option<&'a Arista<Vertice, Peso>>>
+}
+
+impl<'a, Vertice, Peso> Instancia<'a, Vertice, Peso>
+where Vertice: VerticeT, Peso: PesoT + Ord
+{
+    ///
+    /// POST: Instancia del grafo. None si alguna arista que no sea un lazo no tiene peso
+    /// 
+    fn new(grafo: &'a Grafo<Vertice, Peso>) -> Option<Self>
+    {
+        let vertices = grafo.get_vertices();
+        let ids: HashMap<&Vertice, usize> = vertices.iter().enumerate()
+                                                .map(|(i, v)| (*v, i))
+                                                .collect();
+        let n = vertices.len();
+        let mut mejores: Vec<Option<&Arista<Vertice, Peso>>> = vec![None; n * n];
+        for arista in grafo.get_aristas()
+        {
+            if let Some((v, w)) = arista.get_vertices()
+            {
+                if v == w
+                {
+                    continue;
+                }
+                let peso = arista.get_peso()?;
+                let (i, j) = (ids[v], ids[w]);
+                if mejores[i * n + j].is_none_or(|x| peso < x.get_peso().unwrap())
+                {
+                    mejores[i * n + j] = Some(arista);
+                    mejores[j * n + i] = Some(arista);
+                }
+            }
+        }
+        Some(Self { vertices, ids, mejores })
+    }
+
+    fn size(&self) -> usize
+    {
+        self.vertices.len()
+    }
+
+    fn arista(&self, i: usize, j: usize) -> Option<&'a Arista<Vertice, Peso>>
+    {
+        self.mejores[i * self.size() + j]
+    }
+
+    fn distancia(&self, i: usize, j: usize) -> Option<isize>
+    {
+        self.arista(i, j).map(|x| x.get_peso().unwrap().to_isize())
+    }
+
+    ///
+    /// POST: Identificadores de la ruta. None si no pasa exactamente una vez por cada vertice del grafo
+    /// 
+    fn orden(&self, ruta: &[&Vertice]) -> Option<Vec<usize>>
+    {
+        let mut visitados = vec![false; self.size()];
+        let mut orden = vec![];
+        for v in ruta
+        {
+            let id = *self.ids.get(v)?;
+            if visitados[id]
+            {
+                return None;
+            }
+            visitados[id] = true;
+            orden.push(id);
+        }
+        match orden.len() == self.size() {
+            true => Some(orden),
+            false => None
+        }
+    }
+
+    ///
+    /// POST: Ruta con su coste. None si dos vertices consecutivos no son adyacentes
+    /// 
+    fn ruta(&self, orden: &[usize]) -> Option<RutaViajante<'a, Vertice, Peso>>
+    {
+        let mut coste = Peso::elemento_neutro();
+        if orden.len() > 1
+        {
+            for (pos, i) in orden.iter().enumerate()
+            {
+                let j = orden[(pos + 1) % orden.len()];
+                coste = coste.suma(self.arista(*i, j)?.get_peso().unwrap());
+            }
+        }
+        Some((orden.iter().map(|i| self.vertices[*i]).collect(), coste))
+    }
+}
+
+///
+/// PRE: Grafo con pesos y vertice inicial
+/// POST: Ruta que visita en cada paso el vertice no visitado mas cercano, con su coste. None si el vertice no existe,
+/// falta algun peso o la ruta no puede completarse con aristas del grafo
+/// NOTA: Coste O(n^2) en tiempo y memoria. Las distancias se comparan con `PesoT::to_isize`
+/// 
+pub fn vecino_mas_cercano<'a, Vertice, Peso>(grafo: &'a Grafo<Vertice, Peso>, inicio: &Vertice) -> Option<RutaViajante<'a, Vertice, Peso>>
+where Vertice: VerticeT, Peso: PesoT + Ord
+{
+    let instancia = Instancia::new(grafo)?;
+    let n = instancia.size();
+    let mut actual = *instancia.ids.get(inicio)?;
+    let mut visitados = vec![false; n];
+    visitados[actual] = true;
+    let mut orden = vec![actual];
+    for _ in 1..n
+    {
+        actual = (0..n).filter(|j| !visitados[*j])
+                    .filter_map(|j| instancia.distancia(actual, j).map(|d| (d, j)))
+                    .min()?.1;
+        visitados[actual] = true;
+        orden.push(actual);
+    }
+    instancia.ruta(&orden)
+}
+
+///
+/// PRE: Grafo con pesos
+/// POST: Ruta construida insertando en cada paso el vertice que menos aumenta el coste, en su mejor posicion,
+/// con su coste. None si falta algun peso o la ruta no puede completarse con aristas del grafo
+/// NOTA: Se guarda la mejor insercion de cada vertice y solo se recalcula entera cuando desaparece su arista.
+/// Usa O(n^2) memoria. Las distancias se comparan con `PesoT::to_isize`
+/// 
+pub fn insercion_mas_barata<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>) -> Option<RutaViajante<'_, Vertice, Peso>>
+where Vertice: VerticeT, Peso: PesoT + Ord
+{
+    let instancia = Instancia::new(grafo)?;
+    let n = instancia.size();
+    if n <= 1
+    {
+        return instancia.ruta(&(0..n).collect::<Vec<usize>>());
+    }
+
+    // La ruta inicial une el primer vertice con el mas cercano. siguiente[i] es el sucesor de i en la ruta
+    let cercano = (1..n).filter_map(|j| instancia.distancia(0, j).map(|d| (d, j))).min()?.1;
+    let mut siguiente = vec![0; n];
+    siguiente[cercano] = 0;
+    siguiente[0] = cercano;
+    let mut en_ruta = vec![0, cercano];
+    let mut visitados = vec![false; n];
+    visitados[0] = true;
+    visitados[cercano] = true;
+
+    // Aumento de coste de insertar k tras i
+    let coste = |k: usize, i: usize, siguiente: &[usize]| -> Option<isize> {
+        let j = siguiente[i];
+        Some(instancia.distancia(i, k)? + instancia.distancia(k, j)? - instancia.distancia(i, j)?)
+    };
+    // mejor[k]: menor aumento de coste y vertice tras el que insertar k
+    let mut mejor: Vec<Option<(isize, usize)>> = vec![None; n];
+    for k in (0..n).filter(|k| !visitados[*k])
+    {
+        mejor[k] = en_ruta.iter().filter_map(|i| coste(k, *i, &siguiente).map(|c| (c, *i))).min();
+    }
+
+    for _ in 2..n
+    {
+        let k = (0..n).filter(|k| !visitados[*k])
+                    .filter_map(|k| mejor[k].map(|x| (x, k)))
+                    .min()?.1;
+        let i = mejor[k].unwrap().1;
+        let j = siguiente[i];
+        siguiente[i] = k;
+        siguiente[k] = j;
+        visitados[k] = true;
+        en_ruta.push(k);
+
+        for u in (0..n).filter(|u| !visitados[*u])
+        {
+            mejor[u] = match mejor[u] {
+                Some((_, x)) if x != i => [mejor[u], coste(u, i, &siguiente).map(|c| (c, i)), coste(u, k, &siguiente).map(|c| (c, k))]
+                                            .into_iter().flatten().min(),
+                _ => en_ruta.iter().filter_map(|x| coste(u, *x, &siguiente).map(|c| (c, *x))).min()
+            };
+        }
+    }
+
+    let mut orden = vec![0];
+    while orden.len() < n
+    {
+        orden.push(siguiente[*orden.last().unwrap()]);
+    }
+    instancia.ruta(&orden)
+}
+
+///
+/// PRE: Grafo conexo con pesos
+/// POST: Ruta de Christofides con su coste. None si falta algun peso, el grafo no es conexo o la ruta no puede
+/// completarse con aristas del grafo
+/// NOTA: Se empareja a los vertices de grado impar del arbol de peso minimo, se construye un circuito euleriano y
+/// se eliminan los vertices repetidos. En un grafo completo con desigualdad triangular el coste es a lo sumo 3/2 del
+/// optimo. El emparejamiento de peso minimo es cubico en el numero de vertices impares. Usa O(n^2) memoria
+/// 
+pub fn christofides<Vertice, Peso>(grafo: &Grafo<Vertice, Peso>) -> Option<RutaViajante<'_, Vertice, Peso>>
+where Vertice: VerticeT, Peso: PesoT + Ord
+{
+    let instancia = Instancia::new(grafo)?;
+    let n = instancia.size();
+    if n == 0
+    {
+        return instancia.ruta(&[]);
+    }
+    let bosque = bosque_peso_minimo(grafo)?;
+    if bosque.len() != 1
+    {
+        return None;
+    }
+
+    // Aristas del arbol mas las del emparejamiento, tomadas del grafo
+    let mut grados = vec![0; n];
+    let mut aristas: Vec<&Arista<Vertice, Peso>> = vec![];
+    for arista in bosque[0].get_aristas()
+    {
+        if let Some((v, w)) = arista.get_vertices()
+        {
+            let (i, j) = (instancia.ids[v], instancia.ids[w]);
+            grados[i] += 1;
+            grados[j] += 1;
+            aristas.push(instancia.arista(i, j).unwrap());
+        }
+    }
+    let impares: Vec<usize> = (0..n).filter(|i| grados[*i] % 2 == 1).collect();
+    let distancias: Vec<Vec<Option<isize>>> = impares.iter()
+                    .map(|i| impares.iter().map(|j| if i == j { None } else { instancia.distancia(*i, *j) }).collect())
+                    .collect();
+    for (i, j) in emparejamiento_minimo(&distancias)?
+    {
+        aristas.push(instancia.arista(impares[i], impares[j]).unwrap());
+    }
+
+    // Circuito euleriano y atajos sobre los vertices repetidos
+    let mut actual = instancia.vertices[0];
+    let mut visitados = vec![false; n];
+    visitados[0] = true;
+    let mut orden = vec![0];
+    for arista in hierholzer(&aristas, actual)
+    {
+        actual = arista.other(actual).unwrap();
+        let id = instancia.ids[actual];
+        if !visitados[id]
+        {
+            visitados[id] = true;
+            orden.push(id);
+        }
+    }
+    instancia.ruta(&orden)
+}
+
+///
+/// PRE: Grafo con pesos y ruta que pasa una vez por cada vertice
+/// POST: Ruta mejorada invirtiendo tramos mientras se reduzca el coste, con su coste. None si la ruta no es valida
+/// NOTA: Coste O(n^2) por pasada y O(n^2) memoria. Solo se consideran los cambios que usan aristas del grafo.
+/// Las distancias se comparan con `PesoT::to_isize`
+/// 
+pub fn dos_opt<'a, Vertice, Peso>(grafo: &'a Grafo<Vertice, Peso>, ruta: &[&Vertice]) -> Option<RutaViajante<'a, Vertice, Peso>>
+where Vertice: VerticeT, Peso: PesoT + Ord
+{
+    let instancia = Instancia::new(grafo)?;
+    let mut orden = instancia.orden(ruta)?;
+    instancia.ruta(&orden)?;
+    let n = orden.len();
+    let mut mejora = n >= 4;
+    while mejora
+    {
+        mejora = false;
+        for i in 0..n - 2
+        {
+            for j in i + 2..n
+            {
+                if i == 0 && j == n - 1
+                {
+                    continue;
+                }
+                let (a, b, c, d) = (orden[i], orden[i + 1], orden[j], orden[(j + 1) % n]);
+                let actual = (instancia.distancia(a, b), instancia.distancia(c, d));
+                let cambio = (instancia.distancia(a, c), instancia.distancia(b, d));
+                if let ((Some(ab), Some(cd)), (Some(ac), Some(bd))) = (actual, cambio)
+                {
+                    if ac + bd < ab + cd
+                    {
+                        orden[i + 1..=j].reverse();
+                        mejora = true;
+                    }
+                }
+            }
+        }
+    }
+    instancia.ruta(&orden)
+}
+
+///
+/// PRE: Grafo con pesos y ruta que pasa una vez por cada vertice
+/// POST: Ruta mejorada moviendo tramos de hasta tres vertices, en cualquier sentido, mientras se reduzca el coste,
+/// con su coste. None si la ruta no es valida
+/// NOTA: Coste O(n^2) por pasada y O(n^2) memoria. Solo se consideran los cambios que usan aristas del grafo.
+/// Las distancias se comparan con `PesoT::to_isize`
+/// 
+pub fn or_opt<'a, Vertice, Peso>(grafo: &'a Grafo<Vertice, Peso>, ruta: &[&Vertice]) -> Option<RutaViajante<'a, Vertice, Peso>>
+where Vertice: VerticeT, Peso: PesoT + Ord
+{
+    let instancia = Instancia::new(grafo)?;
+    let mut orden = instancia.orden(ruta)?;
+    instancia.ruta(&orden)?;
+    let n = orden.len();
+    let d = |i: usize, j: usize| instancia.distancia(i, j);
+    let mut mejora = true;
+    while mejora
+    {
+        mejora = false;
+        for longitud in (1..=3).filter(|l| l + 3 <= n)
+        {
+            for inicio in 0..n
+            {
+                // El tramo y el resto de la ruta, empezando tras el tramo
+                let tramo: Vec<usize> = (0..longitud).map(|x| orden[(inicio + x) % n]).collect();
+                let resto: Vec<usize> = (longitud..n).map(|x| orden[(inicio + x) % n]).collect();
+                let (primero, ultimo) = (tramo[0], tramo[longitud - 1]);
+                let (p, q) = (resto[resto.len() - 1], resto[0]);
+                let ahorro = match (d(p, primero), d(ultimo, q), d(p, q)) {
+                    (Some(x), Some(y), Some(z)) => x + y - z,
+                    _ => { continue; }
+                };
+
+                let mut eleccion = None;
+                for (k, par) in resto.windows(2).enumerate()
+                {
+                    let (x, y) = (par[0], par[1]);
+                    let xy = match d(x, y) {
+                        Some(xy) => xy,
+                        None => { continue; }
+                    };
+                    for invertido in [false, true]
+                    {
+                        let (a, b) = if invertido { (ultimo, primero) } else { (primero, ultimo) };
+                        if let (Some(xa), Some(by)) = (d(x, a), d(b, y))
+                        {
+                            if xa + by - xy < ahorro && eleccion.is_none_or(|(c, _, _)| xa + by - xy < c)
+                            {
+                                eleccion = Some((xa + by - xy, k, invertido));
+                            }
+                        }
+                    }
+                }
+
+                if let Some((_, k, invertido)) = eleccion
+                {
+                    let mut tramo = tramo;
+                    if invertido
+                    {
+                        tramo.reverse();
+                    }
+                    orden = resto[..=k].iter().chain(tramo.iter()).chain(resto[k + 1..].iter()).copied().collect();
+                    mejora = true;
+                }
+            }
+        }
+    }
+    instancia.ruta(&orden)
+}
@@ -0,0 +1,157 @@
+use crate::grafo_rs::{Arista, Grafo};
+use super::*;
+
+///
+/// Grafo completo sobre los puntos dados con la distancia de Manhattan
+/// 
+fn grafo_completo(puntos: &[(isize, isize)]) -> Grafo<usize, isize>
+{
+    let mut aristas = vec![];
+    for (i, p) in puntos.iter().enumerate()
+    {
+        for (j, q) in puntos.iter().enumerate().skip(i + 1)
+        {
+            aristas.push(Arista::arista(i, j, Some((p.0 - q.0).abs() + (p.1 - q.1).abs())));
+        }
+    }
+    Grafo::from_aristas(aristas)
+}
+
+fn puntos_pseudoaleatorios(n: usize) -> Vec<(isize, isize)>
+{
+    let mut semilla: usize = 12345;
+    let mut siguiente = || {
+        semilla = semilla.wrapping_mul(1103515245).wrapping_add(12345) % (1 << 31);
+        (semilla % 1000) as isize
+    };
+    (0..n).map(|_| (siguiente(), siguiente())).collect()
+}
+
+///
+/// Comprueba que la ruta visite cada vertice una vez y que su coste sea correcto. Devuelve el coste
+/// 
+fn comprobar(puntos: &[(isize, isize)], ruta: RutaViajante<'_, usize, isize>) -> isize
+{
+    let (vertices, coste) = ruta;
+    let mut ordenados: Vec<usize> = vertices.iter().map(|v| **v).collect();
+    ordenados.sort();
+    assert_eq!(ordenados, (0..puntos.len()).collect::<Vec<usize>>());
+    let mut suma = 0;
+    for (pos, v) in vertices.iter().enumerate()
+    {
+        let (p, q) = (puntos[**v], puntos[*vertices[(pos + 1) % vertices.len()]]);
+        suma += (p.0 - q.0).abs() + (p.1 - q.1).abs();
+    }
+    assert_eq!(coste, suma);
+    coste
+}
+
+///
+/// Coste de la ruta optima por fuerza bruta, fijando el primer vertice
+/// 
+fn optimo(puntos: &[(isize, isize)]) -> isize
+{
+    fn permutar(puntos: &[(isize, isize)], ruta: &mut Vec<usize>, usados: &mut [bool], mejor: &mut isize)
+    {
+        let d = |i: usize, j: usize| (puntos[i].0 - puntos[j].0).abs() + (puntos[i].1 - puntos[j].1).abs();
+        if ruta.len() == puntos.len()
+        {
+            let coste: isize = (0..ruta.len()).map(|x| d(ruta[x], ruta[(x + 1) % ruta.len()])).sum();
+            *mejor = (*mejor).min(coste);
+            return;
+        }
+        for v in 1..puntos.len()
+        {
+            if !usados[v]
+            {
+                usados[v] = true;
+                ruta.push(v);
+                permutar(puntos, ruta, usados, mejor);
+                ruta.pop();
+                usados[v] = false;
+            }
+        }
+    }
+    let mut mejor = isize::MAX;
+    permutar(puntos, &mut vec![0], &mut vec![false; puntos.len()], &mut mejor);
+    mejor
+}
+
+#[test]
+fn test_construccion()
+{
+    let puntos = [(0, 0), (4, 1), (8, 0), (9, 5), (6, 9), (1, 8), (3, 4), (7, 3)];
+    let g = grafo_completo(&puntos);
+    let optimo = optimo(&puntos);
+
+    let vecino = comprobar(&puntos, vecino_mas_cercano(&g, &0).expect("Existe ruta"));
+    let insercion = comprobar(&puntos, insercion_mas_barata(&g).expect("Existe ruta"));
+    let christofides = comprobar(&puntos, christofides(&g).expect("Existe ruta"));
+    assert!(vecino >= optimo && insercion >= optimo && christofides >= optimo);
+    assert!(2 * christofides <= 3 * optimo, "Christofides debe estar a menos de 3/2 del optimo");
+    assert!(insercion <= 2 * optimo);
+
+    assert!(vecino_mas_cercano(&g, &20).is_none());
+}
+
+#[test]
+fn test_mejora_local()
+{
+    // Las diagonales del cuadrado se cruzan
+    let puntos = [(0, 0), (0, 10), (10, 0), (10, 10)];
+    let g = grafo_completo(&puntos);
+    let (ruta, coste) = dos_opt(&g, &[&0, &3, &1, &2]).expect("Ruta valida");
+    assert_eq!(coste, 40);
+    assert_eq!(comprobar(&puntos, (ruta, coste)), 40);
+
+    // Un vertice fuera de lugar
+    let puntos = [(0, 0), (1, 0), (2, 0), (3, 0), (3, 1), (2, 1), (1, 1), (0, 1)];
+    let g = grafo_completo(&puntos);
+    let (_, inicial) = or_opt(&g, &[&0, &1, &2, &3, &4, &5, &6, &7]).unwrap();
+    assert_eq!(inicial, 8);
+    let ruta = or_opt(&g, &[&0, &5, &1, &2, &3, &4, &6, &7]).expect("Ruta valida");
+    assert_eq!(comprobar(&puntos, ruta), 8);
+
+    assert!(dos_opt(&g, &[&0, &1, &2]).is_none(), "La ruta debe pasar por todos los vertices");
+    assert!(or_opt(&g, &[&0, &1, &2, &3, &4, &5, &6, &6]).is_none(), "La ruta no puede repetir vertices");
+}
+
+#[test]
+fn test_grafo_grande()
+{
+    let puntos = puntos_pseudoaleatorios(200);
+    let g = grafo_completo(&puntos);
+
+    let (ruta, vecino) = vecino_mas_cercano(&g, &0).unwrap();
+    let (ruta, dos) = dos_opt(&g, &ruta).unwrap();
+    assert!(dos <= vecino);
+    let ruta = or_opt(&g, &ruta).unwrap();
+    assert!(comprobar(&puntos, ruta) <= dos);
+
+    comprobar(&puntos, insercion_mas_barata(&g).unwrap());
+    comprobar(&puntos, christofides(&g).unwrap());
+}
+
+#[test]
+fn test_grafo_incompleto()
+{
+    let camino: Grafo<usize, isize> = Grafo::from_aristas([Arista::arista(0, 1, Some(1)),
+                                                            Arista::arista(1, 2, Some(1))].to_vec());
+    assert!(vecino_mas_cercano(&camino, &0).is_none());
+    assert!(insercion_mas_barata(&camino).is_none());
+    assert!(christofides(&camino).is_none());
+
+    let disconexo: Grafo<usize, isize> = Grafo::from_aristas([Arista::arista(0, 1, Some(1)),
+                                                               Arista::arista(2, 3, Some(1))].to_vec());
+    assert!(christofides(&disconexo).is_none());
+
+    let sin_peso: Grafo<usize, isize> = Grafo::from_aristas([Arista::arista(0, 1, None)].to_vec());
+    assert!(insercion_mas_barata(&sin_peso).is_none());
+
+    // Con uno o dos vertices la ruta es trivial
+    let unico: Grafo<usize, isize> = Grafo::from_aristas([Arista::vertice(0)].to_vec());
+    assert_eq!(christofides(&unico).unwrap().1, 0);
+    let par: Grafo<usize, isize> = Grafo::from_aristas([Arista::arista(0, 1, Some(3))].to_vec());
+    assert_eq!(insercion_mas_barata(&par).unwrap().1, 6);
+    assert_eq!(christofides(&par).unwrap().1, 6);
+}
@@ -10,6 +10,11 @@ where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
     p: PhantomData<Peso>
 }
 
+///
+/// Coleccion de arboles disjuntos, uno por cada componente conexa
+/// 
+pub type Bosque<Graf, Vertice, Peso> = Vec<Arbol<Graf, Vertice, Peso>>;
+
 impl<Graf, Vertice, Peso> GrafoT<Vertice, Peso> for Arbol<Graf, Vertice, Peso>
 where Graf: GrafoT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
 {
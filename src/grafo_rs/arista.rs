@@ -23,7 +23,7 @@ where Vertice: VerticeT, Peso: PesoT {
     ///
     /// PRE: Dos vertices y un peso opcional
     /// POST: Arista formada por dichos vertices y el peso opcional
-    ///
+    /// 
     fn arista(v: Vertice, w: Vertice, p: Option<Peso>) -> Self
     {
         Self::Arista(v, w, p)
@@ -99,6 +99,19 @@ where Vertice: VerticeT, Peso: PesoT {
         false
     }
 
+    ///
+    /// PRE: Arista
+    /// POST: Referencia al vertice si es vertice aislado. None eoc
+    /// 
+    fn get_vertice_aislado(&self) -> Option<&Vertice>
+    {
+        if let Self::VerticeAislado(v) = &self
+        {
+            return Some(v);
+        }
+        None
+    }
+
     ///
     /// PRE: Arista
     /// POST: Referencia a peso si lo contiene. None eoc
@@ -66,6 +66,11 @@ where Vertice: VerticeT, Peso: PesoT
     /// 
     fn es_vetice_aislado(&self, v: &Vertice) -> bool;
 
+    ///
+    /// Funcion miembro. Devuelve una referencia a su vertice si es un vertice aislado. None eoc
+    /// 
+    fn get_vertice_aislado(&self) -> Option<&Vertice>;
+
     ///
     /// Funcion miembro. Si es una arista devuelve su posible Peso. None si es vertice aislado
     /// 
@@ -1,12 +1,15 @@
+use std::hash::Hash;
+
 ///
 /// Trait que simplifica la caracteristica comun de los vertices
+/// NOTA: Se requiere `Eq` y `Hash` para poder indexar los vertices de los grafos
 /// 
-pub trait VerticeT : Clone + PartialEq
+pub trait VerticeT : Clone + Eq + Hash
 {}
 
 ///
 /// PRE: Tipos separados por comas
-/// POST: Se implementa VerticeT para los tipos dados. Los tipos deberan implementar `Clone` y `PartialEq`
+/// POST: Se implementa VerticeT para los tipos dados. Los tipos deberan implementar `Clone`, `Eq` y `Hash`
 /// 
 #[macro_export]
 macro_rules! impl_vertice_t {
@@ -18,4 +21,4 @@ macro_rules! impl_vertice_t {
     };
 }
 
-impl_vertice_t!(u32, i32, u8, isize, usize, char);
+impl_vertice_t!(u32, i32, u8, isize, usize, char, String);
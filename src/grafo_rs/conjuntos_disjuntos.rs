@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod tests;
+
+///
+/// Estructura de conjuntos disjuntos (union-find) sobre los elementos 0..n
+/// NOTA: Se usa compresion de caminos y union por rango
+/// 
+pub struct ConjuntosDisjuntos
+{
+    padres: Vec<usize>,
+    rangos: Vec<usize>,
+    num_conjuntos: usize
+}
+
+impl ConjuntosDisjuntos
+{
+    ///
+    /// PRE: Numero de elementos
+    /// POST: Estructura con n conjuntos unitarios {0}, {1}, ..., {n - 1}
+    /// 
+    pub fn new(n: usize) -> Self
+    {
+        Self {
+            padres: (0..n).collect(),
+            rangos: vec![0; n],
+            num_conjuntos: n
+        }
+    }
+
+    ///
+    /// POST: Numero de elementos
+    /// 
+    pub fn size(&self) -> usize
+    {
+        self.padres.len()
+    }
+
+    ///
+    /// POST: Numero de conjuntos disjuntos
+    /// 
+    pub fn num_conjuntos(&self) -> usize
+    {
+        self.num_conjuntos
+    }
+
+    ///
+    /// PRE: Elemento menor que size()
+    /// POST: Representante del conjunto que contiene a x
+    /// 
+    pub fn buscar(&mut self, x: usize) -> usize
+    {
+        let mut raiz = x;
+        while self.padres[raiz] != raiz
+        {
+            raiz = self.padres[raiz];
+        }
+        // Compresion de caminos
+        let mut actual = x;
+        while self.padres[actual] != raiz
+        {
+            let siguiente = self.padres[actual];
+            self.padres[actual] = raiz;
+            actual = siguiente;
+        }
+        raiz
+    }
+
+    ///
+    /// PRE: Elementos menores que size()
+    /// POST: Se unen los conjuntos de x e y. true si estaban separados, false eoc
+    /// 
+    pub fn unir(&mut self, x: usize, y: usize) -> bool
+    {
+        let raiz_x = self.buscar(x);
+        let raiz_y = self.buscar(y);
+        if raiz_x == raiz_y
+        {
+            return false;
+        }
+        match self.rangos[raiz_x].cmp(&self.rangos[raiz_y]) {
+            std::cmp::Ordering::Less => { self.padres[raiz_x] = raiz_y; },
+            std::cmp::Ordering::Greater => { self.padres[raiz_y] = raiz_x; },
+            std::cmp::Ordering::Equal => {
+                self.padres[raiz_y] = raiz_x;
+                self.rangos[raiz_x] += 1;
+            }
+        }
+        self.num_conjuntos -= 1;
+        true
+    }
+
+    ///
+    /// PRE: Elementos menores que size()
+    /// POST: true si x e y estan en el mismo conjunto. false eoc
+    /// 
+    pub fn mismo_conjunto(&mut self, x: usize, y: usize) -> bool
+    {
+        self.buscar(x) == self.buscar(y)
+    }
+}
+
+impl Clone for ConjuntosDisjuntos
+{
+    fn clone(&self) -> Self {
+        Self {
+            padres: self.padres.clone(),
+            rangos: self.rangos.clone(),
+            num_conjuntos: self.num_conjuntos
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use super::ConjuntosDisjuntos;
+
+#[test]
+fn test_union_busqueda()
+{
+    let mut conjuntos = ConjuntosDisjuntos::new(6);
+    assert_eq!(conjuntos.num_conjuntos(), 6);
+    assert!(conjuntos.unir(0, 1));
+    assert!(conjuntos.unir(2, 3));
+    assert!(conjuntos.unir(1, 3));
+    assert!(!conjuntos.unir(0, 2), "Ya estan en el mismo conjunto");
+    assert_eq!(conjuntos.num_conjuntos(), 3);
+
+    assert!(conjuntos.mismo_conjunto(0, 3));
+    assert!(!conjuntos.mismo_conjunto(0, 4));
+    assert_eq!(conjuntos.buscar(2), conjuntos.buscar(1));
+    assert_ne!(conjuntos.buscar(4), conjuntos.buscar(5));
+    assert_eq!(conjuntos.size(), 6);
+}
@@ -62,6 +62,13 @@ where Vertice: VerticeT, Peso: PesoT
         }
     }
 
+    fn get_vertice_aislado(&self) -> Option<&Vertice> {
+        match &self {
+            Self::VerticeAislado(v) => Some(v),
+            _ => None
+        }
+    }
+
     fn get_vertices(&self) -> Option<(&Vertice, &Vertice)> {
         if let Self::Diarista(u, v, _) = &self
         {
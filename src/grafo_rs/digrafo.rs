@@ -1,11 +1,13 @@
 use crate::grafo_rs::{Arista, AristaT, Diarista, Grafo, GrafoT, NoPeso, PesoT, VerticeT};
+use crate::grafo_rs::grafo_t::Indice;
 
 #[cfg(test)]
 mod tests;
 
 pub struct Digrafo<Vertice, Peso = NoPeso>
 where Vertice: VerticeT, Peso: PesoT {
-    lista_arcos: Vec<Diarista<Vertice, Peso>>
+    lista_arcos: Vec<Diarista<Vertice, Peso>>,
+    indice: Indice<Vertice>
 }
 
 impl<Vertice, Peso> Digrafo<Vertice, Peso>
@@ -29,38 +31,11 @@ where Vertice: VerticeT, Peso: PesoT
     /// 
     pub fn grado_entrada(&self, v: &Vertice) -> Option<usize>
     {
-        let mut res = None;
-        for arco in self.lista_arcos.iter()
-        {
-            match arco {
-                Diarista::Diarista(u1, u2, _) => {
-                    if u2 == v
-                    {
-                        res = match res {
-                            None => Some(1),
-                            Some(d) => Some(d + 1)
-                        };
-                    }
-                    else if let None = res
-                    {
-                        if u1 == v
-                        {
-                            res = Some(0);
-                        }
-                    }
-                },
-                Diarista::VerticeAislado(u) => {
-                    if u == v
-                    {
-                        if let None = res
-                        {
-                            res = Some(0);
-                        }
-                    }
-                }
-            };
-        }
-        res
+        let res = self.indice.incidencias(v)?.iter()
+                        .filter_map(|pos| self.lista_arcos[*pos].get_vertices())
+                        .filter(|(_, u2)| *u2 == v)
+                        .count();
+        Some(res)
     }
 
     ///
@@ -69,40 +44,52 @@ where Vertice: VerticeT, Peso: PesoT
     /// 
     pub fn grado_salida(&self, v: &Vertice) -> Option<usize>
     {
-        let mut res = None;
+        let res = self.indice.incidencias(v)?.iter()
+                        .filter_map(|pos| self.lista_arcos[*pos].get_vertices())
+                        .filter(|(u1, _)| *u1 == v)
+                        .count();
+        Some(res)
+    }
+
+    ///
+    /// PRE: Vertice
+    /// POST: Vector con referencias a los extremos finales de los arcos que salen de v. None si no esta en el digrafo
+    /// 
+    pub fn sucesores<'a>(&'a self, v: &Vertice) -> Option<Vec<&'a Vertice>>
+    {
+        let res = self.indice.incidencias(v)?.iter()
+                        .filter_map(|pos| self.lista_arcos[*pos].get_vertices())
+                        .filter(|(u1, _)| *u1 == v)
+                        .map(|(_, u2)| u2)
+                        .collect();
+        Some(res)
+    }
+
+    ///
+    /// PRE: Vertice
+    /// POST: Vector con referencias a los extremos iniciales de los arcos que llegan a v. None si no esta en el digrafo
+    /// 
+    pub fn predecesores<'a>(&'a self, v: &Vertice) -> Option<Vec<&'a Vertice>>
+    {
+        let res = self.indice.incidencias(v)?.iter()
+                        .filter_map(|pos| self.lista_arcos[*pos].get_vertices())
+                        .filter(|(_, u2)| *u2 == v)
+                        .map(|(u1, _)| u1)
+                        .collect();
+        Some(res)
+    }
 
-        for arco in self.lista_arcos.iter()
+    ///
+    /// PRE: Digrafo modificable y arco propio
+    /// POST: Se añade el arco si no estaba en el digrafo, actualizando el indice
+    /// 
+    fn insertar_arco(&mut self, arco: Diarista<Vertice, Peso>)
+    {
+        if !self.indice.contiene(&self.lista_arcos, &arco)
         {
-            match arco {
-                Diarista::Diarista(u1, u2, _) => {
-                    if u1 == v
-                    {
-                        res = match res {
-                            None => Some(1),
-                            Some(d) => Some(d + 1)
-                        };
-                    }
-                    else if let None = res
-                    {
-                        if u2 == v
-                        {
-                            res = Some(0);
-                        }
-                    }
-                },
-                Diarista::VerticeAislado(u) => {
-                    if u == v
-                    {
-                        if let None = res
-                        {
-                            res = Some(0);
-                        }
-                    }
-                }
-            };
+            self.indice.registrar(&arco, self.lista_arcos.len());
+            self.lista_arcos.push(arco);
         }
-
-        res
     }
 }
 
@@ -112,34 +99,29 @@ where Vertice: VerticeT, Peso: PesoT {
 
     fn new() -> Self {
         Self{
-            lista_arcos: vec![]
+            lista_arcos: vec![],
+            indice: Indice::new()
         }
     }
 
-    fn from_aristas(lista: Vec<Diarista<Vertice, Peso>>) -> Self 
+    fn size(&self) -> usize
     {
-        let mut lista_arcos = Vec::with_capacity(lista.len());
+        self.indice.size()
+    }
 
-        for diarista in lista.into_iter()
-        {
-            if !lista_arcos.contains(&diarista)
-            {
-                lista_arcos.push(diarista);
-            }
-        }
-        Self{
-            lista_arcos
-        }
+    fn from_aristas(lista: Vec<Diarista<Vertice, Peso>>) -> Self 
+    {
+        let mut digrafo = Self::new();
+        digrafo.add_aristas(lista);
+        digrafo
     }
 
     fn add_aristas(&mut self, lista: Vec<Diarista<Vertice, Peso>>) 
     {
+        self.lista_arcos.reserve(lista.len());
         for diarista in lista.into_iter()
         {
-            if !self.lista_arcos.contains(&diarista)
-            {
-                self.lista_arcos.push(diarista);
-            }
+            self.insertar_arco(diarista);
         }
     }
 
@@ -155,37 +137,15 @@ where Vertice: VerticeT, Peso: PesoT {
 
     fn get_vertices(&self) -> Vec<&Vertice> 
     {
-        let mut lista_vertices: Vec<&Vertice> = vec![];
-
-        for diarista in self.get_aristas().iter()
-        {
-            match diarista {
-                Diarista::Diarista(v, w, _) => {
-                    if !lista_vertices.contains(&v) 
-                    {
-                        lista_vertices.push(v);
-                    }
-                    if !lista_vertices.contains(&w)
-                    {
-                        lista_vertices.push(w);
-                    }
-                },
-                Diarista::VerticeAislado(v) => {
-                    if !lista_vertices.contains(&v)
-                    {
-                        lista_vertices.push(v);
-                    }
-                }
-            }
-        }
-        lista_vertices    
+        self.indice.vertices()
     }
 
     fn remove_arista(&mut self, arista: &Diarista<Vertice, Peso>) 
     {
-        if let Some(index) = self.get_aristas().iter().position(|x| x == arista)
+        if let Some(index) = self.indice.buscar(&self.lista_arcos, arista)
         {
             let e = self.lista_arcos.remove(index);
+            self.indice = Indice::from_aristas(&self.lista_arcos);
             if let Diarista::Diarista(v, w, _) = e
             {
                 self.add_vertices(vec![v, w]);
@@ -195,33 +155,58 @@ where Vertice: VerticeT, Peso: PesoT {
 
     fn remove_vertice(&mut self, v: &Vertice) 
     {
-        let arista_indexes: Vec<usize> = self.get_aristas().iter().enumerate()
-                                            .filter(|x| 
-                                                    x.1.arista_contiene_vertice(v) || x.1.es_vetice_aislado(v))
-                                            .map(|x| x.0)
-                                            .collect();
+        let arista_indexes = match self.indice.incidencias(v) {
+            Some(incidencias) => incidencias.clone(),
+            None => { return; }
+        };
 
-        let mut i: usize = 0;
-        for index in arista_indexes.into_iter()
+        let mut vecinos: Vec<Vertice> = vec![];
+        for (i, index) in arista_indexes.into_iter().enumerate()
         {
             let e = self.lista_arcos.remove(index - i);
             if !e.es_vetice_aislado(v)
             {
-                self.lista_arcos.push(Diarista::vertice(e.other(v).unwrap().clone()));
+                vecinos.push(e.other(v).unwrap().clone());
             }
-            i += 1;
         }
+        self.indice = Indice::from_aristas(&self.lista_arcos);
+        vecinos.retain(|x| x != v);
+        self.add_vertices(vecinos);
+    }
+
+    fn aristas_por_vertice(&self, v: &Vertice) -> Vec<&Diarista<Vertice, Peso>>
+    {
+        match self.indice.incidencias(v) {
+            Some(incidencias) => incidencias.iter()
+                        .map(|pos| &self.lista_arcos[*pos])
+                        .filter(|x| x.arista_contiene_vertice(v))
+                        .collect(),
+            None => vec![]
+        }
+    }
+
+    fn grado(&self, v: &Vertice) -> Option<usize>
+    {
+        let mut result: usize = 0;
+        for pos in self.indice.incidencias(v)?.iter()
+        {
+            if let Some((v1, v2)) = self.lista_arcos[*pos].get_vertices()
+            {
+                result += if v1 == v2 { 2 } else { 1 };
+            }
+        }
+        Some(result)
     }
 
     ///
-    /// PRE: Falso
-    /// POST: Nada
-    /// NOTA: No se define entorno para Digrafo
+    /// PRE: Vertice
+    /// POST: Vector con referencias a los sucesores de v. None si no esta en el digrafo
+    /// NOTA: Se toma como entorno el entorno de salida, vease [`Digrafo::predecesores`] para el de entrada
     /// 
-    fn entorno<'a>(&'a self, _: &Vertice) -> Option<Vec<&Vertice>>
+    fn entorno<'a>(&'a self, v: &Vertice) -> Option<Vec<&'a Vertice>>
     where Diarista<Vertice, Peso>: 'a 
     {
-        unimplemented!("Digrafo no tiene entorno definido")
+        self.sucesores(v)
     }
 }
 
@@ -230,7 +215,8 @@ where Vertice: VerticeT, Peso: PesoT
 {
     fn clone(&self) -> Self {
         Self{
-            lista_arcos: self.lista_arcos.clone()
+            lista_arcos: self.lista_arcos.clone(),
+            indice: self.indice.clone()
         }
     }
 }
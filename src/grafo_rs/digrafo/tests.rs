@@ -63,3 +63,46 @@ fn test_grados()
 
     assert_eq!(digrafo.grado_entrada(&20), None, "Vertice inexistente");
 }
+
+#[test]
+fn test_indice_tras_modificaciones()
+{
+    let mut digrafo: Digrafo<i32> = Digrafo::from_aristas([Diarista::arista_sin_peso(1, 2),
+                                                            Diarista::arista_sin_peso(2, 1),
+                                                            Diarista::arista_sin_peso(2, 3),
+                                                            Diarista::arista_sin_peso(3, 3),
+                                                            Diarista::arista_sin_peso(1, 2)].to_vec());
+
+    assert_eq!(digrafo.get_aristas().len(), 4, "No se repiten arcos");
+    assert_eq!(digrafo.get_vertices(), vec![&1, &2, &3]);
+    assert_eq!(digrafo.grado(&3), Some(3));
+    assert_eq!(digrafo.grado_entrada(&3), Some(2));
+
+    digrafo.remove_vertice(&2);
+    assert_eq!(digrafo.grado_salida(&1), Some(0));
+    assert_eq!(digrafo.grado_entrada(&3), Some(1));
+    assert_eq!(digrafo.grado(&2), None);
+
+    digrafo.add_aristas(vec![Diarista::arista_sin_peso(3, 1)]);
+    assert_eq!(digrafo.grado_entrada(&1), Some(1));
+    assert_eq!(digrafo.size(), 2);
+}
+
+#[test]
+fn test_sucesores_predecesores()
+{
+    let digrafo: Digrafo<i32> = Digrafo::from_aristas([Diarista::arista_sin_peso(1, 2),
+                                                            Diarista::arista_sin_peso(1, 3),
+                                                            Diarista::arista_sin_peso(3, 2),
+                                                            Diarista::arista_sin_peso(2, 2),
+                                                            Diarista::vertice(10)].to_vec());
+
+    assert_eq!(digrafo.sucesores(&1), Some(vec![&2, &3]));
+    assert_eq!(digrafo.predecesores(&1), Some(vec![]));
+    assert_eq!(digrafo.sucesores(&2), Some(vec![&2]), "Lazo");
+    assert_eq!(digrafo.predecesores(&2), Some(vec![&1, &3, &2]));
+    assert_eq!(digrafo.sucesores(&10), Some(vec![]), "Vertice aislado");
+    assert_eq!(digrafo.predecesores(&20), None, "Vertice inexistente");
+
+    assert_eq!(digrafo.entorno(&3), digrafo.sucesores(&3), "El entorno es el entorno de salida");
+}
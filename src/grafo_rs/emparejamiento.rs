@@ -1,5 +1,7 @@
-use crate::grafo_rs::{AristaT, PesoT, VerticeT};
+use crate::grafo_rs::{AristaT, GrafoT, PesoT, VerticeT};
+use crate::grafo_rs::algoritmo::edmonds;
 
+use std::collections::HashMap;
 use std::marker::PhantomData;
 
 #[cfg(test)]
@@ -45,6 +47,22 @@ where Arista: AristaT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
         })
     }
 
+    ///
+    /// PRE: Lista de referencias a aristas sin vertices aislados ni extremos en comun
+    /// 
+    /// POST: El emparejamiento formado por esas aristas
+    /// 
+    /// NOTA: No comprueba la validez. Para algoritmos cuyo resultado ya es un emparejamiento por construccion
+    /// 
+    pub(crate) fn from_disjuntas(aristas: Vec<&'a Arista>) -> Self
+    {
+        Self {
+            aristas,
+            v: PhantomData,
+            p: PhantomData
+        }
+    }
+
     ///
     /// POST: Devuelve el numero de aristas del emparejamiento
     /// 
@@ -53,6 +71,14 @@ where Arista: AristaT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
         self.aristas.len()
     }
 
+    ///
+    /// POST: Devuelve las aristas del emparejamiento
+    /// 
+    pub fn get_aristas(&self) -> &Vec<&'a Arista>
+    {
+        &self.aristas
+    }
+
     ///
     /// PRE: Referencia a vertice
     /// 
@@ -88,13 +114,15 @@ where Arista: AristaT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
     /// 
     /// POST: true si puede añadirla, false eoc
     /// 
+    /// NOTA: Solo se puede añadir una arista que no sea un lazo y cuyos extremos sean ambos libres
+    /// 
     pub fn agregar_arista(&mut self, e: &'a Arista) -> bool
     {
         let (u, v) = match e.get_vertices() {
             Some((u, v)) => (u, v),
             None => {return false;}
         };
-        if !self.es_libre(u) && !self.es_libre(v) {
+        if u != v && self.es_libre(u) && self.es_libre(v) {
             self.aristas.push(e);
             return true;
         }
@@ -112,6 +140,98 @@ where Arista: AristaT<Vertice, Peso>, Vertice: VerticeT, Peso: PesoT
         self.aristas.retain(|x| *x != e);
     }
 
+    ///
+    /// PRE: Grafo que contiene las aristas del emparejamiento
+    /// 
+    /// POST: Camino de aumento: aristas de un camino entre dos vertices libres que alternan entre no
+    /// emparejadas y emparejadas, en orden de recorrido. None si el emparejamiento es maximo en el grafo
+    /// 
+    /// NOTA: Busqueda de Edmonds con contraccion de flores. O(n³). Los lazos del grafo se ignoran
+    /// 
+    pub fn camino_aumento<Graf>(&self, grafo: &'a Graf) -> Option<Vec<&'a Arista>>
+    where Graf: GrafoT<Vertice, Peso, Arista = Arista>
+    {
+        let vertices = grafo.get_vertices();
+        let ids: HashMap<&Vertice, usize> = vertices.iter().enumerate()
+                                                .map(|(i, v)| (*v, i))
+                                                .collect();
+        // Arista entre cada par de vertices, la del emparejamiento si esta emparejado
+        let mut aristas: HashMap<(usize, usize), &'a Arista> = HashMap::new();
+        let mut pareja: Vec<Option<usize>> = vec![None; vertices.len()];
+        for arista in self.aristas.iter()
+        {
+            let (u, v) = arista.get_vertices().unwrap();
+            let (i, j) = (ids[u], ids[v]);
+            pareja[i] = Some(j);
+            pareja[j] = Some(i);
+            aristas.insert((i.min(j), i.max(j)), arista);
+        }
+        let mut vecinos: Vec<Vec<usize>> = vec![vec![]; vertices.len()];
+        for arista in grafo.get_aristas()
+        {
+            if let Some((u, v)) = arista.get_vertices()
+            {
+                let (i, j) = (ids[u], ids[v]);
+                if i != j && !aristas.contains_key(&(i.min(j), i.max(j)))
+                {
+                    aristas.insert((i.min(j), i.max(j)), arista);
+                    vecinos[i].push(j);
+                    vecinos[j].push(i);
+                }
+            }
+        }
+        for (i, j) in pareja.iter().enumerate().filter_map(|(i, x)| x.filter(|j| i < *j).map(|j| (i, j)))
+        {
+            vecinos[i].push(j);
+            vecinos[j].push(i);
+        }
+
+        let camino = edmonds::camino_aumento(vecinos, pareja)?;
+        Some(camino.windows(2).map(|x| aristas[&(x[0].min(x[1]), x[0].max(x[1]))]).collect())
+    }
+
+    ///
+    /// PRE: Camino alternado de aumento
+    /// 
+    /// POST: Sustituye el emparejamiento por su diferencia simetrica con el camino y devuelve true.
+    /// Si el resultado no es un emparejamiento o no tiene exactamente una arista mas, devuelve false y no lo modifica
+    /// 
+    pub fn aumentar(&mut self, camino: &[&'a Arista]) -> bool
+    where Arista: PartialEq
+    {
+        let mut aristas: Vec<&'a Arista> = self.aristas.iter()
+                                            .filter(|x| !camino.contains(x))
+                                            .copied()
+                                            .collect();
+        aristas.extend(camino.iter().filter(|x| !self.aristas.contains(x)));
+        if aristas.len() != self.size() + 1
+        {
+            return false;
+        }
+        match Self::new(aristas) {
+            Some(nuevo) => {
+                self.aristas = nuevo.aristas;
+                true
+            },
+            None => false
+        }
+    }
+
+    ///
+    /// PRE: Otro emparejamiento
+    /// 
+    /// POST: Aristas que estan en uno solo de los dos emparejamientos. Forman caminos y ciclos alternados
+    /// 
+    pub fn diferencia_simetrica(&self, otro: &Self) -> Vec<&'a Arista>
+    where Arista: PartialEq
+    {
+        self.aristas.iter()
+            .filter(|x| !otro.aristas.contains(x))
+            .chain(otro.aristas.iter().filter(|x| !self.aristas.contains(x)))
+            .copied()
+            .collect()
+    }
+
 }
 
 impl<'a, Arista, Vertice, Peso> PartialEq for Emparejamiento<'a, Arista, Vertice, Peso>
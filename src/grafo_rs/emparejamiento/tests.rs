@@ -1,4 +1,4 @@
-use crate::grafo_rs::{Arista, AristaT, NoPeso};
+use crate::grafo_rs::{Arista, AristaT, Grafo, GrafoT, NoPeso};
 
 use super::Emparejamiento;
 
@@ -36,3 +36,100 @@ fn test_creacion_emparejamiento_2()
     assert!(Emparejamiento::new(aristas.iter().collect::<Vec<&Arista<i32, NoPeso>>>()).is_none(),
         "Contiene un vertice aislado");
 }
+
+#[test]
+fn test_agregar_arista()
+{
+    let aristas: Vec<Arista<i32, NoPeso>> = vec![Arista::arista_sin_peso(1, 2), Arista::arista_sin_peso(2, 3),
+        Arista::arista_sin_peso(3, 4), Arista::arista_sin_peso(5, 5), Arista::vertice(6)];
+    let mut emp = Emparejamiento::new(vec![]).unwrap();
+
+    assert!(emp.agregar_arista(&aristas[0]));
+    assert!(!emp.agregar_arista(&aristas[1]), "El vertice 2 ya esta saturado");
+    assert!(emp.agregar_arista(&aristas[2]));
+    assert!(!emp.agregar_arista(&aristas[3]), "Un lazo no puede emparejarse");
+    assert!(!emp.agregar_arista(&aristas[4]), "Un vertice aislado no es una arista");
+    assert!(emp.size() == 2);
+    assert!(emp.recorrer(&4) == Some(&3));
+}
+
+#[test]
+fn test_camino_aumento()
+{
+    // Camino 1-2-3-4 con la arista central emparejada
+    let g: Grafo<i32, NoPeso> = Grafo::from_aristas(vec![Arista::arista_sin_peso(1, 2),
+        Arista::arista_sin_peso(2, 3), Arista::arista_sin_peso(3, 4)]);
+    let mut emp = Emparejamiento::new(vec![&g.get_aristas()[1]]).unwrap();
+    // Un camino alternado de longitud par no aumenta el emparejamiento
+    assert!(!emp.aumentar(&[&g.get_aristas()[0], &g.get_aristas()[1]]));
+    assert!(emp.recorrer(&2) == Some(&3));
+    let camino = emp.camino_aumento(&g).expect("Los extremos son libres");
+    assert!(camino.len() == 3);
+    assert!(*camino[1] == Arista::arista_sin_peso(2, 3));
+    assert!(emp.aumentar(&camino));
+    assert!(emp.size() == 2);
+    assert!(!emp.es_libre(&2) && emp.recorrer(&1) == Some(&2));
+    assert!(emp.camino_aumento(&g).is_none());
+
+    // Un camino que no alterna no se aplica
+    assert!(!emp.aumentar(&[&g.get_aristas()[1]]));
+    assert!(emp.size() == 2);
+
+    // Quitar una arista emparejada no es un aumento
+    let emparejada = emp.get_aristas()[0];
+    assert!(!emp.aumentar(&[emparejada]));
+    assert!(emp.size() == 2);
+}
+
+#[test]
+fn test_camino_aumento_flor()
+{
+    // Triangulo 1-2-3 con colas 0-1 y 3-4-5: el unico camino de aumento rodea el triangulo
+    let g: Grafo<i32, NoPeso> = Grafo::from_aristas(vec![Arista::arista_sin_peso(0, 1),
+        Arista::arista_sin_peso(1, 2), Arista::arista_sin_peso(2, 3), Arista::arista_sin_peso(3, 1),
+        Arista::arista_sin_peso(3, 4), Arista::arista_sin_peso(4, 5)]);
+    let mut emp = Emparejamiento::new(vec![&g.get_aristas()[1], &g.get_aristas()[4]]).unwrap();
+    while let Some(camino) = emp.camino_aumento(&g)
+    {
+        let antes = emp.size();
+        assert!(camino.len() % 2 == 1);
+        assert!(emp.aumentar(&camino));
+        assert!(emp.size() == antes + 1);
+    }
+    assert!(emp.size() == 3);
+
+    // Partiendo del vacio, aumentar hasta que no haya caminos da un emparejamiento maximo
+    let mut aristas = vec![];
+    for i in 0..5
+    {
+        aristas.push(Arista::arista_sin_peso(i, (i + 1) % 5));
+        aristas.push(Arista::arista_sin_peso(i, i + 5));
+        aristas.push(Arista::arista_sin_peso(i + 5, (i + 2) % 5 + 5));
+    }
+    let petersen: Grafo<i32, NoPeso> = Grafo::from_aristas(aristas);
+    let mut emp = Emparejamiento::new(vec![]).unwrap();
+    while let Some(camino) = emp.camino_aumento(&petersen)
+    {
+        assert!(emp.aumentar(&camino));
+    }
+    assert!(emp.size() == 5);
+}
+
+#[test]
+fn test_diferencia_simetrica()
+{
+    let aristas: Vec<Arista<i32, NoPeso>> = vec![Arista::arista_sin_peso(1, 2), Arista::arista_sin_peso(2, 3),
+        Arista::arista_sin_peso(3, 4), Arista::arista_sin_peso(5, 6)];
+    let emp1 = Emparejamiento::new(vec![&aristas[0], &aristas[2], &aristas[3]]).unwrap();
+    let emp2 = Emparejamiento::new(vec![&aristas[1], &aristas[3]]).unwrap();
+
+    let diferencia = emp1.diferencia_simetrica(&emp2);
+    assert!(diferencia.len() == 3);
+    assert!(!diferencia.contains(&&aristas[3]));
+    assert!(emp2.diferencia_simetrica(&emp2).is_empty());
+
+    // La diferencia con un emparejamiento mayor contiene un camino de aumento
+    let mut emp3 = emp2.clone();
+    assert!(emp3.aumentar(&emp2.diferencia_simetrica(&emp1)));
+    assert!(emp3.size() == 3 && emp3.recorrer(&4) == Some(&3));
+}
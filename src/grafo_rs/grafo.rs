@@ -1,6 +1,7 @@
 use crate::grafo_rs::{Arista, AristaT, 
                     NoPeso, PesoT, 
                     VerticeT, GrafoT};
+use crate::grafo_rs::grafo_t::Indice;
 
 pub mod bipartido;
 pub use bipartido::Bipartido;
@@ -11,6 +12,24 @@ mod tests;
 pub struct Grafo<Vertice, Peso = NoPeso> 
 where Vertice: VerticeT, Peso: PesoT {
     lista_aristas: Vec<Arista<Vertice, Peso>>,
+    indice: Indice<Vertice>
+}
+
+impl<Vertice, Peso> Grafo<Vertice, Peso>
+where Vertice: VerticeT, Peso: PesoT
+{
+    ///
+    /// PRE: Grafo modificable y arista propia
+    /// POST: Se añade la arista si no estaba en el grafo, actualizando el indice
+    /// 
+    fn insertar_arista(&mut self, arista: Arista<Vertice, Peso>)
+    {
+        if !self.indice.contiene(&self.lista_aristas, &arista)
+        {
+            self.indice.registrar(&arista, self.lista_aristas.len());
+            self.lista_aristas.push(arista);
+        }
+    }
 }
 
 impl<Vertice, Peso> GrafoT<Vertice, Peso> for Grafo<Vertice, Peso>
@@ -24,28 +43,29 @@ where Vertice: VerticeT, Peso: PesoT {
     fn new() -> Self 
     {
         Self{
-            lista_aristas: vec![]
+            lista_aristas: vec![],
+            indice: Indice::new()
         }    
     }
 
+    ///
+    /// PRE: Grafo
+    /// POST: Numero de vertices del grafo
+    /// 
+    fn size(&self) -> usize
+    {
+        self.indice.size()
+    }
+
     ///
     /// PRE: Vector con Aristas propias
     /// POST: Grafo contruido a partir de dichas aristas, no repitiendo ninguna
     /// 
     fn from_aristas(lista: Vec<Arista<Vertice, Peso>>) -> Self
     {
-        let mut lista_aristas: Vec<Arista<Vertice, Peso>> = vec![];
-
-        for arista in lista.into_iter()
-        {
-            if !lista_aristas.contains(&arista)
-            {
-                lista_aristas.push(arista);
-            }
-        }
-        Self{
-            lista_aristas
-        }
+        let mut grafo = Self::new();
+        grafo.add_aristas(lista);
+        grafo
     }
 
     ///
@@ -57,10 +77,7 @@ where Vertice: VerticeT, Peso: PesoT {
         self.lista_aristas.reserve(lista.len());
         for arista in lista.into_iter()
         {
-            if !self.lista_aristas.contains(&arista)
-            {
-                self.lista_aristas.push(arista);
-            }
+            self.insertar_arista(arista);
         }
     }
 
@@ -85,33 +102,11 @@ where Vertice: VerticeT, Peso: PesoT {
 
     ///
     /// PRE: Grafo
-    /// POST: Vector con referencias a los vertices del grafo
+    /// POST: Vector con referencias a los vertices del grafo, por orden de aparicion
     /// 
     fn get_vertices(&self) -> Vec<&Vertice>
     {
-        let mut result: Vec<&Vertice> = vec![];
-        for arista in self.lista_aristas.iter()
-        {
-            match arista {
-                Arista::Arista(v, w, _) => {
-                    if !result.contains(&v)
-                    {
-                        result.push(v);
-                    }
-                    if !result.contains(&w)
-                    {
-                        result.push(w);
-                    }
-                },
-                Arista::VerticeAislado(v) => {
-                    if !result.contains(&v)
-                    {
-                        result.push(v);
-                    }
-                }
-            }
-        }
-        result
+        self.indice.vertices()
     }
 
     ///
@@ -122,9 +117,10 @@ where Vertice: VerticeT, Peso: PesoT {
     {
         // Tenemos en cuenta que la arista tiene una unica ocurrencia en la lista,
         // ya que asi se implementa en los metodos de adicion
-        if let Some(index) = self.lista_aristas.iter().position(|x| *x == *e)
+        if let Some(index) = self.indice.buscar(&self.lista_aristas, e)
         {
             let e = self.lista_aristas.remove(index);
+            self.indice = Indice::from_aristas(&self.lista_aristas);
             if let Arista::Arista(v0, w0, _) = e
             {
                 self.add_vertices(vec![v0, w0]);
@@ -132,31 +128,82 @@ where Vertice: VerticeT, Peso: PesoT {
         }
     }
 
+    ///
+    /// PRE: Vertice que se va a eliminar
+    /// POST: Grafo sin el vertice. Sus vecinos se conservan
+    /// 
     fn remove_vertice(&mut self, v: &Vertice)
     {
-        let lista_aristas_index: Vec<usize> = self.lista_aristas.iter().enumerate()
-                                                                .filter(|(_, e)| {
-                                                                    e.arista_contiene_vertice(v) || e.es_vetice_aislado(v)
-                                                                })
-                                                                .map(|(i, _)| i)
-                                                                .collect();
-        let mut i: usize = 0;
-        for index in lista_aristas_index
+        let incidencias = match self.indice.incidencias(v) {
+            Some(incidencias) => incidencias.clone(),
+            None => { return; }
+        };
+        let mut vecinos: Vec<Vertice> = vec![];
+        for (i, index) in incidencias.into_iter().enumerate()
         {
+            // Las posiciones estan ordenadas de forma creciente
             let e = self.lista_aristas.remove(index - i);
             if let Arista::Arista(v0, w0, _) = e
             {
                 if *v == v0 
                 {
-                    self.lista_aristas.push(Arista::VerticeAislado(w0));
+                    vecinos.push(w0);
                 }
                 else 
                 {
-                    self.lista_aristas.push(Arista::VerticeAislado(v0));    
+                    vecinos.push(v0);
                 }
             }
-            i += 1;
         }
+        self.indice = Indice::from_aristas(&self.lista_aristas);
+        vecinos.retain(|x| x != v);
+        self.add_vertices(vecinos);
+    }
+
+    ///
+    /// PRE: Vertice al que calcular su entorno
+    /// POST: Vector con referencias a los vertices adyacentes a v. None si no pertenece al grafo
+    /// 
+    fn entorno<'a>(&'a self, v: &Vertice) -> Option<Vec<&'a Vertice>>
+    where Arista<Vertice, Peso>: 'a
+    {
+        let res = self.indice.incidencias(v)?.iter()
+                        .filter_map(|pos| self.lista_aristas[*pos].other(v))
+                        .collect();
+        Some(res)
+    }
+
+    ///
+    /// PRE: El vertice tiene que pertenecer al grafo
+    /// POST: Vector con referencias a las aristas
+    /// 
+    fn aristas_por_vertice(&self, v: &Vertice) -> Vec<&Arista<Vertice, Peso>>
+    {
+        match self.indice.incidencias(v) {
+            Some(incidencias) => incidencias.iter()
+                        .map(|pos| &self.lista_aristas[*pos])
+                        .filter(|x| x.arista_contiene_vertice(v))
+                        .collect(),
+            None => vec![]
+        }
+    }
+
+    ///
+    /// PRE: El vertice al que calcular su grado
+    /// POST: Valor opcional con el grado. Si el vertice no esta incluido en el grafo, devuelve None
+    /// 
+    fn grado(&self, v: &Vertice) -> Option<usize>
+    {
+        let mut result: usize = 0;
+        for pos in self.indice.incidencias(v)?.iter()
+        {
+            if let Some((v1, v2)) = self.lista_aristas[*pos].get_vertices()
+            {
+                // Los lazos cuentan dos veces
+                result += if v1 == v2 { 2 } else { 1 };
+            }
+        }
+        Some(result)
     }
 }
 
@@ -165,7 +212,8 @@ where Vertice: VerticeT, Peso: PesoT {
     fn clone(&self) -> Self 
     {
         Self {
-            lista_aristas: self.lista_aristas.clone()
+            lista_aristas: self.lista_aristas.clone(),
+            indice: self.indice.clone()
         }
     }
 }
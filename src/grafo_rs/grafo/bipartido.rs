@@ -1,3 +1,5 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
 use crate::grafo_rs::{Arista, AristaT, GrafoT, PesoT, VerticeT};
 
 use super::Grafo;
@@ -10,9 +12,11 @@ where Vertice: VerticeT, Peso: PesoT
 {
     /*
      * Almacenaremos las aristas de la forma (x,y)
-     * Garantizaremos que no tiene vertices aislados
+     * Los vertices aislados solo aparecen en su particion
      */    
     lista_aristas: Vec<Arista<Vertice, Peso>>,
+    vertices_x: Vec<Vertice>,
+    vertices_y: Vec<Vertice>
 }
 
 impl<Vertice, Peso> Bipartido<Vertice, Peso>
@@ -25,60 +29,132 @@ where Vertice: VerticeT, Peso: PesoT
     /// 
     pub fn from_grafo(grafo: &Grafo<Vertice, Peso>) -> Option<Self>
     {
-        let mut vertices_x: Vec<&Vertice> = vec![];
-        let mut vertices_y: Vec<&Vertice> = vec![];
+        Self::try_from_grafo(grafo).ok()
+    }
 
-        let mut lista_aristas: Vec<Arista<Vertice, Peso>> = vec![];
-        for arista in grafo.get_aristas().iter()
-        {            
-            let (v1, v2) = match arista.get_vertices() {
-                Some((v1, v2)) => (v1, v2),
-                None => {continue;}
-            };
-
-            if vertices_x.contains(&v1) {
-                if vertices_x.contains(&v2) {
-                    return None;
-                }
-                if !vertices_y.contains(&v2) {
-                    vertices_y.push(v2);
-                }
-                lista_aristas.push(Arista::Arista(v1.clone(), v2.clone(), arista.get_peso().cloned()));
+    ///
+    /// PRE: Referencia a Grafo
+    /// 
+    /// POST: Si el grafo dado es bipartido, genera un Bipartido a partir de el, incluyendo los vertices aislados.
+    /// Eoc, error con las aristas de un ciclo impar en orden de recorrido
+    /// 
+    /// NOTA: 2-coloracion por busqueda en anchura en cada componente. La raiz de cada componente va a la
+    /// primera particion
+    /// 
+    pub fn try_from_grafo(grafo: &Grafo<Vertice, Peso>) -> Result<Self, Vec<Arista<Vertice, Peso>>>
+    {
+        let vertices = grafo.get_vertices();
+        let ids: HashMap<&Vertice, usize> = vertices.iter().enumerate()
+                                                .map(|(i, v)| (*v, i))
+                                                .collect();
+        // Lado de cada vertice (false para la primera particion) y arista por la que se alcanza
+        let mut lados: Vec<Option<bool>> = vec![None; vertices.len()];
+        let mut padres: Vec<Option<(usize, &Arista<Vertice, Peso>)>> = vec![None; vertices.len()];
+        let mut vertices_x = vec![];
+        let mut vertices_y = vec![];
+
+        for raiz in 0..vertices.len()
+        {
+            if lados[raiz].is_some()
+            {
+                continue;
             }
-            else if vertices_y.contains(&v1) {
-                if vertices_y.contains(&v2) {
-                    return None;
-                }
-                if !vertices_x.contains(&v2) {
-                    vertices_x.push(v2);
+            lados[raiz] = Some(false);
+            let mut cola = VecDeque::from([raiz]);
+            while let Some(v) = cola.pop_front()
+            {
+                let lado = lados[v].unwrap();
+                match lado {
+                    false => vertices_x.push(vertices[v].clone()),
+                    true => vertices_y.push(vertices[v].clone())
+                };
+                for arista in grafo.aristas_accesibles(vertices[v])
+                {
+                    let w = ids[arista.other(vertices[v]).unwrap()];
+                    match lados[w] {
+                        None => {
+                            lados[w] = Some(!lado);
+                            padres[w] = Some((v, arista));
+                            cola.push_back(w);
+                        },
+                        Some(otro) if otro == lado => {
+                            return Err(Self::ciclo_impar(&padres, arista, v, w));
+                        },
+                        _ => {}
+                    }
                 }
-                lista_aristas.push(Arista::Arista(v2.clone(), v1.clone(), arista.get_peso().cloned()));
             }
-            else if vertices_x.contains(&v2) {
-                vertices_y.push(v1);
-                lista_aristas.push(Arista::Arista(v2.clone(), v1.clone(), arista.get_peso().cloned()));
-            }
-            else if vertices_y.contains(&v2) {
-                vertices_x.push(v1);
-                lista_aristas.push(Arista::Arista(v1.clone(), v2.clone(), arista.get_peso().cloned()));
-            }
-            else {
-                vertices_x.push(v1);
-                vertices_y.push(v2);
-                lista_aristas.push(Arista::Arista(v1.clone(), v2.clone(), arista.get_peso().cloned()));    
+        }
+
+        let mut lista_aristas = vec![];
+        for arista in grafo.get_aristas().iter()
+        {
+            if let Some((v1, v2)) = arista.get_vertices()
+            {
+                let (x, y) = match lados[ids[v1]] {
+                    Some(false) => (v1, v2),
+                    _ => (v2, v1)
+                };
+                lista_aristas.push(Arista::Arista(x.clone(), y.clone(), arista.get_peso().cloned()));
             }
         }
-        Some(Self {
-            lista_aristas
+        Ok(Self {
+            lista_aristas,
+            vertices_x,
+            vertices_y
         })
     }
 
+    ///
+    /// Funcion auxiliar. Ciclo impar formado por una arista entre dos vertices del mismo lado y los caminos
+    /// del arbol de busqueda hasta su antecesor comun
+    /// 
+    fn ciclo_impar(padres: &[Option<(usize, &Arista<Vertice, Peso>)>], arista: &Arista<Vertice, Peso>, v: usize, w: usize)
+        -> Vec<Arista<Vertice, Peso>>
+    {
+        let antecesores = |mut x: usize| {
+            let mut camino = vec![x];
+            while let Some((padre, _)) = padres[x]
+            {
+                camino.push(padre);
+                x = padre;
+            }
+            camino
+        };
+        let (desde_v, desde_w) = (antecesores(v), antecesores(w));
+        let comun = *desde_v.iter().find(|x| desde_w.contains(x)).unwrap();
+
+        // Arista v-w, camino de w al antecesor comun y camino del antecesor comun a v
+        let mut ciclo = vec![arista.clone()];
+        for x in desde_w.iter().take_while(|x| **x != comun)
+        {
+            ciclo.push(padres[*x].unwrap().1.clone());
+        }
+        let mut vuelta: Vec<Arista<Vertice, Peso>> = desde_v.iter()
+                            .take_while(|x| **x != comun)
+                            .map(|x| padres[*x].unwrap().1.clone())
+                            .collect();
+        vuelta.reverse();
+        ciclo.append(&mut vuelta);
+        ciclo
+    }
+
     ///
     /// POST: Consume el Bipartido y devuelve su [`Grafo`] subyacente
     /// 
     pub fn into_grafo(self) -> Grafo<Vertice, Peso>
     {
-        Grafo::from_aristas(self.lista_aristas)
+        let con_aristas: HashSet<&Vertice> = self.lista_aristas.iter()
+                            .flat_map(|x| { let (v, w) = x.get_vertices().unwrap(); [v, w] })
+                            .collect();
+        let aislados: Vec<Vertice> = self.vertices_x.iter()
+                            .chain(self.vertices_y.iter())
+                            .filter(|v| !con_aristas.contains(v))
+                            .cloned()
+                            .collect();
+        let mut grafo = Grafo::from_aristas(self.lista_aristas);
+        grafo.add_vertices(aislados);
+        grafo
     }
 
     ///
@@ -86,15 +162,7 @@ where Vertice: VerticeT, Peso: PesoT
     /// 
     pub fn get_vertices_x(&self) -> Vec<&Vertice>
     {
-        let mut vertices_x = vec![];
-        for arista in self.lista_aristas.iter()
-        {
-            let v = arista.get_vertices().unwrap().0;
-            if !vertices_x.contains(&v) {
-                vertices_x.push(v);
-            }
-        }
-        vertices_x
+        self.vertices_x.iter().collect()
     }
 
     ///
@@ -102,15 +170,7 @@ where Vertice: VerticeT, Peso: PesoT
     /// 
     pub fn get_vertices_y(&self) -> Vec<&Vertice>
     {
-        let mut vertices_y = vec![];
-        for arista in self.lista_aristas.iter()
-        {
-            let v = arista.get_vertices().unwrap().1;
-            if !vertices_y.contains(&v) {
-                vertices_y.push(v);
-            }
-        }
-        vertices_y
+        self.vertices_y.iter().collect()
     }
 }
 
@@ -132,23 +192,10 @@ where Vertice: VerticeT, Peso: PesoT
     }
 
     fn get_vertices(&self) -> Vec<&Vertice> {
-        let mut result: Vec<&Vertice> = vec![];
-        for arista in self.lista_aristas.iter()
-        {
-            let (x, y) = arista.get_vertices().unwrap();
-            if !result.contains(&x)
-            {
-                result.push(x);
-            }
-            if !result.contains(&y)
-            {
-                result.push(y);
-            }
-        }
-        result
+        self.vertices_x.iter().chain(self.vertices_y.iter()).collect()
     }
 
-    fn entorno<'b>(&'b self, v: &Vertice) -> Option<Vec<&Vertice>>
+    fn entorno<'b>(&'b self, v: &Vertice) -> Option<Vec<&'b Vertice>>
     where Self::Arista: 'b
     {
         let res: Vec<&Vertice> = self.lista_aristas.iter().filter_map(|x| {
@@ -157,8 +204,7 @@ where Vertice: VerticeT, Peso: PesoT
                 _ => None
             }
         }).collect();
-        if res.is_empty() {
-            // No existen vertices aislados en Bipartido
+        if res.is_empty() && !self.vertices_x.contains(v) && !self.vertices_y.contains(v) {
             return None;
         }
         Some(res)
@@ -171,6 +217,8 @@ where Vertice: VerticeT, Peso: PesoT
     fn clone(&self) -> Self {
         Self {
             lista_aristas: self.lista_aristas.clone(),
+            vertices_x: self.vertices_x.clone(),
+            vertices_y: self.vertices_y.clone()
         }
     }
 }
@@ -48,9 +48,56 @@ fn test_grafo_bipartido_partes()
 
     let bipartido = Bipartido::from_grafo(&g).expect("El grafo es bipartido");
 
-    let vertices_esperados_x = vec![&1, &4];
+    let vertices_esperados_x = vec![&1, &4, &10];
     let vertices_esperados_y = vec![&2, &3, &5];
 
     assert!(bipartido.get_vertices_x() == vertices_esperados_x);
     assert!(bipartido.get_vertices_y() == vertices_esperados_y);
 }
+
+#[test]
+fn test_grafo_bipartido_componentes()
+{
+    // La arista 1-3 une dos componentes ya vistas con la misma orientacion
+    let g: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(1, 2),
+        Arista::arista_sin_peso(3, 4), Arista::arista_sin_peso(1, 3), Arista::vertice(7)].to_vec());
+
+    let bipartido = Bipartido::from_grafo(&g).expect("El grafo es bipartido");
+    assert!(bipartido.get_vertices_x() == vec![&1, &4, &7]);
+    assert!(bipartido.get_vertices_y() == vec![&2, &3]);
+    assert_eq!(bipartido.get_vertices().len(), 5);
+    assert_eq!(bipartido.entorno(&7), Some(vec![]));
+
+    let grafo = bipartido.into_grafo();
+    assert_eq!(grafo.size(), 5);
+    assert!(grafo.get_aristas().contains(&Arista::vertice(7)));
+}
+
+#[test]
+fn test_grafo_no_bipartido_ciclo_impar()
+{
+    let g: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(1, 2),
+        Arista::arista_sin_peso(2, 3), Arista::arista_sin_peso(3, 4), Arista::arista_sin_peso(4, 5),
+        Arista::arista_sin_peso(5, 1), Arista::arista_sin_peso(1, 6), Arista::vertice(10)].to_vec());
+
+    let ciclo = match Bipartido::try_from_grafo(&g) {
+        Ok(_) => panic!("El grafo no es bipartido"),
+        Err(ciclo) => ciclo
+    };
+    assert_eq!(ciclo.len(), 5);
+    // Las aristas forman un recorrido cerrado desde alguno de los extremos de la primera
+    let (v, w) = ciclo[0].get_vertices().unwrap();
+    assert!([*v, *w].into_iter().any(|inicio| {
+        let mut actual = Some(inicio);
+        for arista in ciclo.iter()
+        {
+            actual = actual.and_then(|x| arista.other(&x).copied());
+        }
+        actual == Some(inicio)
+    }));
+    assert!(ciclo.iter().all(|x| g.get_aristas().contains(x)));
+
+    let lazo: Grafo<i32, NoPeso> = Grafo::from_aristas([Arista::arista_sin_peso(1, 2),
+        Arista::arista_sin_peso(2, 2)].to_vec());
+    assert!(matches!(Bipartido::try_from_grafo(&lazo), Err(ciclo) if ciclo.len() == 1));
+}
@@ -84,3 +84,29 @@ fn test_sucesion_grados()
 
     assert_eq!(vec![2, 1, 1, 0], grafo0.sucesion_grados());
 }
+
+#[test]
+fn test_indice_tras_modificaciones()
+{
+    let mut grafo0: Grafo<usize, NoPeso> = Grafo::from_aristas((0..1000)
+                                                    .map(|i| Arista::arista_sin_peso(i, (i + 1) % 1000))
+                                                    .collect());
+    assert_eq!(grafo0.size(), 1000);
+    assert_eq!(grafo0.get_aristas().len(), 1000);
+    assert_eq!(grafo0.grado(&500), Some(2));
+    assert_eq!(vec![0, 2], grafo0.entorno(&1).unwrap().into_iter().map(|x| *x).collect::<Vec<usize>>());
+
+    grafo0.add_aristas(vec![Arista::arista_sin_peso(7, 7), Arista::arista_sin_peso(8, 7)]);
+    assert_eq!(grafo0.get_aristas().len(), 1001, "La arista (8, 7) ya estaba en el grafo");
+    assert_eq!(grafo0.grado(&7), Some(4), "Los lazos cuentan dos veces");
+
+    grafo0.remove_vertice(&500);
+    assert_eq!(grafo0.size(), 999);
+    assert_eq!(grafo0.grado(&499), Some(1));
+    assert_eq!(grafo0.entorno(&501).unwrap(), vec![&502]);
+    assert_eq!(grafo0.aristas_por_vertice(&7).len(), 3);
+
+    grafo0.remove_arista(&Arista::arista_sin_peso(7, 7));
+    assert_eq!(grafo0.grado(&7), Some(2));
+    assert_eq!(grafo0.entorno(&500), None);
+}
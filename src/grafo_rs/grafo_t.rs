@@ -1,5 +1,8 @@
 use crate::grafo_rs::{AristaT, VerticeT, PesoT};
 
+pub(crate) mod indice;
+pub(crate) use indice::Indice;
+
 ///
 /// Trait que define las operaciones basicas para grafos
 /// 
@@ -77,7 +80,7 @@ where Vertice: VerticeT, Peso: PesoT
     /// PRE: Vertice al que calcular su entorno
     /// POST: Devuelve un vector con referencias a los vertices adyacentes a v. Si no pertenece al grafo, None
     /// 
-    fn entorno<'a>(&'a self, v: &Vertice) -> Option<Vec<&Vertice>>
+    fn entorno<'a>(&'a self, v: &Vertice) -> Option<Vec<&'a Vertice>>
     where Self::Arista: 'a
     {
         let mut res = vec![];
@@ -114,6 +117,16 @@ where Vertice: VerticeT, Peso: PesoT
         self.get_aristas().iter().filter(|x| x.arista_contiene_vertice(v)).collect()
     }
 
+    ///
+    /// PRE: Vertice
+    /// POST: Vector con referencias a las aristas que pueden recorrerse partiendo de v. Vacio si no pertenece al grafo
+    /// NOTA: En grafos no dirigidos coincide con `aristas_por_vertice`; en digrafos son los arcos de salida
+    /// 
+    fn aristas_accesibles(&self, v: &Vertice) -> Vec<&Self::Arista>
+    {
+        self.aristas_por_vertice(v).into_iter().filter(|x| x.es_accesible(v)).collect()
+    }
+
     ///
     /// PRE: El vertice al que calcular su grado
     /// POST: Valor opcional con el grado. Si el vertice no esta incluido en el grafo, devuelve None
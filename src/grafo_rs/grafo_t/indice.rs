@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+use crate::grafo_rs::{AristaT, PesoT, VerticeT};
+
+///
+/// Estructura auxiliar que indexa una lista de aristas.
+/// Asigna a cada vertice un identificador denso (por orden de aparicion) y guarda,
+/// para cada uno, las posiciones de las entradas de la lista en las que aparece
+/// 
+pub(crate) struct Indice<Vertice>
+where Vertice: VerticeT
+{
+    ids: HashMap<Vertice, usize>,
+    vertices: Vec<Vertice>,
+    /*
+     * incidencias[id] contiene las posiciones de la lista de aristas que contienen al vertice,
+     * incluyendo su entrada como vertice aislado. Los lazos aparecen una unica vez
+     */
+    incidencias: Vec<Vec<usize>>
+}
+
+impl<Vertice> Indice<Vertice>
+where Vertice: VerticeT
+{
+    ///
+    /// PRE: true
+    /// POST: Indice vacio
+    /// 
+    pub fn new() -> Self
+    {
+        Self {
+            ids: HashMap::new(),
+            vertices: vec![],
+            incidencias: vec![]
+        }
+    }
+
+    ///
+    /// PRE: Lista de aristas
+    /// POST: Indice construido a partir de la lista
+    /// 
+    pub fn from_aristas<Arista, Peso>(lista: &[Arista]) -> Self
+    where Arista: AristaT<Vertice, Peso>, Peso: PesoT
+    {
+        let mut indice = Self::new();
+        for (pos, arista) in lista.iter().enumerate()
+        {
+            indice.registrar(arista, pos);
+        }
+        indice
+    }
+
+    ///
+    /// PRE: Vertice
+    /// POST: Identificador del vertice. Si no estaba indexado, se le asigna uno nuevo
+    /// 
+    fn registrar_vertice(&mut self, v: &Vertice) -> usize
+    {
+        if let Some(id) = self.ids.get(v)
+        {
+            return *id;
+        }
+        let id = self.vertices.len();
+        self.ids.insert(v.clone(), id);
+        self.vertices.push(v.clone());
+        self.incidencias.push(vec![]);
+        id
+    }
+
+    ///
+    /// PRE: Arista y su posicion en la lista de aristas
+    /// POST: Se indexa la arista en sus extremos
+    /// 
+    pub fn registrar<Arista, Peso>(&mut self, arista: &Arista, pos: usize)
+    where Arista: AristaT<Vertice, Peso>, Peso: PesoT
+    {
+        match arista.get_vertices() {
+            Some((v, w)) => {
+                let id_v = self.registrar_vertice(v);
+                let id_w = self.registrar_vertice(w);
+                self.incidencias[id_v].push(pos);
+                if id_v != id_w
+                {
+                    self.incidencias[id_w].push(pos);
+                }
+            },
+            None => {
+                let v = arista.get_vertice_aislado().unwrap();
+                let id = self.registrar_vertice(v);
+                self.incidencias[id].push(pos);
+            }
+        }
+    }
+
+    ///
+    /// PRE: Vertice
+    /// POST: Identificador denso del vertice si esta indexado. None eoc
+    /// 
+    pub fn id(&self, v: &Vertice) -> Option<usize>
+    {
+        self.ids.get(v).copied()
+    }
+
+    ///
+    /// POST: Numero de vertices indexados
+    /// 
+    pub fn size(&self) -> usize
+    {
+        self.vertices.len()
+    }
+
+    ///
+    /// POST: Vector con referencias a los vertices, por orden de aparicion
+    /// 
+    pub fn vertices(&self) -> Vec<&Vertice>
+    {
+        self.vertices.iter().collect()
+    }
+
+    ///
+    /// PRE: Vertice
+    /// POST: Posiciones de las entradas de la lista que contienen al vertice. None si no esta indexado
+    /// 
+    pub fn incidencias(&self, v: &Vertice) -> Option<&Vec<usize>>
+    {
+        Some(&self.incidencias[self.id(v)?])
+    }
+
+    ///
+    /// PRE: Lista indexada y arista
+    /// POST: Posicion de la arista en la lista si esta contenida. None eoc
+    /// NOTA: Solo se recorren las incidencias de uno de los extremos
+    /// 
+    pub fn buscar<Arista, Peso>(&self, lista: &[Arista], arista: &Arista) -> Option<usize>
+    where Arista: AristaT<Vertice, Peso>, Peso: PesoT
+    {
+        let v = match arista.get_vertices() {
+            Some((v, _)) => v,
+            None => arista.get_vertice_aislado()?
+        };
+        self.incidencias(v)?.iter().find(|pos| lista[**pos] == *arista).copied()
+    }
+
+    ///
+    /// PRE: Lista indexada y arista
+    /// POST: true si la arista ya esta en la lista. false eoc
+    /// 
+    pub fn contiene<Arista, Peso>(&self, lista: &[Arista], arista: &Arista) -> bool
+    where Arista: AristaT<Vertice, Peso>, Peso: PesoT
+    {
+        self.buscar(lista, arista).is_some()
+    }
+}
+
+impl<Vertice> Clone for Indice<Vertice>
+where Vertice: VerticeT
+{
+    fn clone(&self) -> Self {
+        Self {
+            ids: self.ids.clone(),
+            vertices: self.vertices.clone(),
+            incidencias: self.incidencias.clone()
+        }
+    }
+}
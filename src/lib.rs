@@ -27,9 +27,11 @@ pub mod grafo_rs
     ///
     /// Modulo Arbol. Componentes:
     ///     Arbol: estructura envoltorio que asegura que el grafo contenido es un arbol
+    ///     Bosque: coleccion de arboles
     /// 
     pub mod arbol;
     pub use arbol::Arbol;
+    pub use arbol::Bosque;
 
     ///
     /// Modulo Digrafo. Componentes:
@@ -105,4 +107,11 @@ pub mod grafo_rs
     pub use etiquetado::Etiqueta;
     pub use etiquetado::Etiquetado;
 
+    ///
+    /// Modulo Conjuntos_Disjuntos. Componentes:
+    ///     ConjuntosDisjuntos: estructura union-find sobre indices
+    /// 
+    pub mod conjuntos_disjuntos;
+    pub use conjuntos_disjuntos::ConjuntosDisjuntos;
+
 }